@@ -0,0 +1,617 @@
+//! Analysing the shape of a polynomial over an interval.
+
+use std::cmp::Ordering;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{CalcError, Polynomial, math_helpers};
+
+/// The tolerance to which the roots of derivatives are found when splitting an interval.
+const ROOT_TOLERANCE: f64 = 1e-12;
+
+/// Whether the values of a polynomial grow, shrink or stay the same over an interval.
+///
+/// Formats as `"increasing"`, `"decreasing"`, `"constant"`, `"mixed"` or `"undefined"`.
+///
+/// #### Example
+/// ```rust
+/// use calcucalc::{Monomial, Polynomial, Trend};
+///
+/// let my_polynomial = Polynomial(vec![Monomial { c: 2.0, e: 1.0 }]);
+/// let trend = my_polynomial.trend_over_interval(0.0, 1.0);
+/// assert_eq!(trend, Trend::Increasing);
+/// assert_eq!(trend.to_string(), "increasing");
+/// ```
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum Trend {
+    /// The polynomial strictly increases over the interval.
+    Increasing,
+    /// The polynomial strictly decreases over the interval.
+    Decreasing,
+    /// The polynomial does not change over the interval.
+    Constant,
+    /// The polynomial increases over part of the interval and decreases over another, or it is split by a singularity.
+    Mixed,
+    /// The polynomial is not defined at the start or end of the interval.
+    Undefined,
+}
+
+/// A sub-interval over which a polynomial is monotonic, as returned by `Polynomial::monotonicity()`.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct MonotonicInterval {
+    /// The x-value at which the sub-interval starts.
+    pub start: f64,
+    /// The x-value at which the sub-interval ends.
+    pub end: f64,
+    /// Whether the polynomial increases, decreases or stays constant over the sub-interval.
+    pub trend: Trend,
+}
+
+/// Whether a polynomial curves upwards or downwards over an interval.
+///
+/// Formats as `"concave up"`, `"concave down"` or `"undefined"`.
+///
+/// #### Example
+/// ```rust
+/// use calcucalc::{Concavity, Monomial, Polynomial};
+///
+/// let my_polynomial = Polynomial(vec![Monomial { c: 1.0, e: 2.0 }]);
+/// let concavity = my_polynomial.concavity_over_interval(-1.0, 1.0).unwrap();
+/// assert_eq!(concavity, Concavity::ConcaveUp);
+/// assert_eq!(concavity.to_string(), "concave up");
+/// ```
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum Concavity {
+    /// The second derivative is positive.
+    ConcaveUp,
+    /// The second derivative is negative.
+    ConcaveDown,
+    /// The second derivative is zero, changes sign, or is not defined.
+    Undefined,
+}
+
+/// A sub-interval over which a polynomial has a single concavity, as returned by `Polynomial::concavity()`.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ConcavityInterval {
+    /// The x-value at which the sub-interval starts.
+    pub start: f64,
+    /// The x-value at which the sub-interval ends.
+    pub end: f64,
+    /// Whether the polynomial is concave up or concave down over the sub-interval.
+    pub concavity: Concavity,
+}
+
+/// A candidate inflection point: a root of the second derivative inside the interval being analysed.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, PartialOrd, Serialize)]
+pub struct InflectionPoint {
+    /// The x-value of the point.
+    pub x: f64,
+    /// The value of the polynomial at `x`.
+    pub y: f64,
+    /// Whether the second derivative really changes sign at `x`.
+    ///
+    /// This is `false` for points such as `x = 0` for `x^4`, where the second derivative touches zero but the concavity does not flip.
+    pub sign_changes: bool,
+}
+
+/// The concave-up and concave-down sub-intervals of an interval, together with the points where the concavity may flip.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct ConcavityAnalysis {
+    /// The sub-intervals, in ascending order.
+    pub intervals: Vec<ConcavityInterval>,
+    /// The roots of the second derivative strictly inside the interval, in ascending order.
+    pub inflection_points: Vec<InflectionPoint>,
+}
+
+/// How a polynomial behaves around one of its critical points.
+///
+/// Formats as `"local maximum"`, `"local minimum"` or `"saddle"`.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+pub enum CriticalPointKind {
+    /// The polynomial is greater at the critical point than anywhere close to it.
+    LocalMaximum,
+    /// The polynomial is less at the critical point than anywhere close to it.
+    LocalMinimum,
+    /// The polynomial keeps increasing or decreasing through the critical point, as `x^3` does at `x = 0`.
+    Saddle,
+}
+
+/// A point at which the derivative of a polynomial is zero, as returned by `Polynomial::critical_points()`.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct CriticalPoint {
+    /// The x-value of the point.
+    pub x: f64,
+    /// The value of the polynomial at `x`.
+    pub y: f64,
+    /// Whether the point is a local maximum, a local minimum or a saddle.
+    pub kind: CriticalPointKind,
+}
+
+/// A point at which a polynomial takes its greatest or least value over an interval.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, PartialOrd, Serialize)]
+pub struct Extremum {
+    /// The x-value of the point.
+    pub x: f64,
+    /// The value of the polynomial at `x`.
+    pub y: f64,
+}
+
+/// The critical points of a polynomial over a closed interval, together with its absolute maximum and minimum there.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Extrema {
+    /// The critical points within the interval, in ascending order.
+    pub critical_points: Vec<CriticalPoint>,
+    /// The greatest value over the interval, or `None` if the polynomial grows without bound near a singularity.
+    pub absolute_maximum: Option<Extremum>,
+    /// The least value over the interval, or `None` if the polynomial falls without bound near a singularity.
+    pub absolute_minimum: Option<Extremum>,
+}
+
+impl std::fmt::Display for Trend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Increasing => "increasing",
+            Self::Decreasing => "decreasing",
+            Self::Constant => "constant",
+            Self::Mixed => "mixed",
+            Self::Undefined => "undefined",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl std::fmt::Display for CriticalPointKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::LocalMaximum => "local maximum",
+            Self::LocalMinimum => "local minimum",
+            Self::Saddle => "saddle",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl std::fmt::Display for Concavity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::ConcaveUp => "concave up",
+            Self::ConcaveDown => "concave down",
+            Self::Undefined => "undefined",
+        };
+        write!(f, "{name}")
+    }
+}
+
+/// A piece of an interval over which a derivative keeps a single sign.
+struct Piece {
+    start: f64,
+    end: f64,
+    /// The sign of the derivative within the piece, or `None` if it is not defined there.
+    sign: Option<Ordering>,
+    /// Whether the piece starts at a singularity of the function being analysed.
+    after_pole: bool,
+}
+
+/// Whether `x` lies strictly inside the interval, and not within tolerance of either end.
+fn is_inside(x: f64, start: f64, end: f64) -> bool {
+    start < x
+        && x < end
+        && !math_helpers::is_equal_within_tolerance_to(&x, &start)
+        && !math_helpers::is_equal_within_tolerance_to(&x, &end)
+}
+
+/// Whether the polynomial has a term with a negative exponent, and so a singularity at `x = 0`.
+fn has_pole(polynomial: &Polynomial) -> bool {
+    polynomial
+        .0
+        .iter()
+        .any(|element| element.c != 0_f64 && element.e < 0_f64)
+}
+
+/// Splits `[start, end]` into pieces over which `derivative` keeps a single sign.
+///
+/// The interval is split at the real roots of `derivative`, and at `x = 0` if `function` has a negative exponent and so a singularity there.
+/// The sign within each piece is that of `derivative` at its midpoint.
+fn sign_pieces(
+    function: &Polynomial,
+    derivative: &Polynomial,
+    start: f64,
+    end: f64,
+) -> Result<Vec<Piece>, CalcError> {
+    let mut breakpoints = vec![];
+    if !derivative.0.is_empty() {
+        for root in derivative.real_roots(ROOT_TOLERANCE)? {
+            if is_inside(root.x, start, end) {
+                breakpoints.push((root.x, false));
+            }
+        }
+    }
+    if has_pole(function) && is_inside(0_f64, start, end) {
+        breakpoints.push((0_f64, true));
+    }
+    breakpoints.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut pieces = vec![];
+    let mut piece_start = start;
+    let mut after_pole = false;
+    for (piece_end, is_pole) in breakpoints.into_iter().chain([(end, false)]) {
+        let value = derivative.value(0.5 * (piece_start + piece_end));
+        pieces.push(Piece {
+            start: piece_start,
+            end: piece_end,
+            sign: value.partial_cmp(&0_f64),
+            after_pole,
+        });
+        piece_start = piece_end;
+        after_pole = is_pole;
+    }
+    Ok(pieces)
+}
+
+/// Joins neighbouring pieces with the same sign, except across a singularity.
+fn merge_pieces(pieces: Vec<Piece>) -> Vec<Piece> {
+    let mut merged: Vec<Piece> = vec![];
+    for piece in pieces {
+        match merged.last_mut() {
+            Some(last) if !piece.after_pole && last.sign == piece.sign => last.end = piece.end,
+            _ => merged.push(piece),
+        }
+    }
+    merged
+}
+
+impl Polynomial {
+    /// Splits an interval into the sub-intervals over which the polynomial strictly increases, strictly decreases or stays constant.
+    ///
+    /// The interval is split at the critical points, where the derivative is zero, and the sign of the derivative is then checked within each piece.
+    /// Neighbouring pieces with the same trend are joined, so a critical point where the derivative does not change sign (such as `x = 0` for `x^3`) does not split the interval.
+    /// Pieces are never joined across `x = 0` if the polynomial has a negative exponent, because it is undefined there.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{MonotonicInterval, Monomial, Polynomial, Trend};
+    ///
+    /// let my_polynomial = Polynomial(vec![Monomial { c: 1.0, e: 2.0 }]);
+    /// assert_eq!(my_polynomial.monotonicity(-1.0, 2.0).unwrap(), vec![
+    ///     MonotonicInterval { start: -1.0, end: 0.0, trend: Trend::Decreasing },
+    ///     MonotonicInterval { start: 0.0, end: 2.0, trend: Trend::Increasing },
+    /// ]);
+    ///
+    /// let my_polynomial = Polynomial(vec![Monomial { c: 1.0, e: 3.0 }]);
+    /// assert_eq!(my_polynomial.monotonicity(-1.0, 2.0).unwrap(), vec![
+    ///     MonotonicInterval { start: -1.0, end: 2.0, trend: Trend::Increasing },
+    /// ]);
+    /// ```
+    ///
+    /// While it is recommended to order the start and end x-values in ascending order, this function will automatically swap them if they are not.
+    ///
+    /// ## Errors
+    ///
+    /// If the derivative has an exponent of x which is not an integer, its critical points cannot be found and an error is returned.
+    pub fn monotonicity(&self, start: f64, end: f64) -> Result<Vec<MonotonicInterval>, CalcError> {
+        // Validate the start and end x-values are in the correct order,
+        // and swap them if they are not.
+        let (start_x, end_x) = if start > end {
+            (end, start)
+        } else {
+            (start, end)
+        };
+
+        let function = self.simplified()?;
+        let derivative = function.derivative()?;
+        if start_x >= end_x {
+            return Ok(vec![MonotonicInterval {
+                start: start_x,
+                end: end_x,
+                trend: Trend::Constant,
+            }]);
+        }
+
+        let pieces = sign_pieces(&function, &derivative, start_x, end_x)?;
+        Ok(merge_pieces(pieces)
+            .into_iter()
+            .map(|piece| MonotonicInterval {
+                start: piece.start,
+                end: piece.end,
+                trend: match piece.sign {
+                    Some(Ordering::Greater) => Trend::Increasing,
+                    Some(Ordering::Less) => Trend::Decreasing,
+                    Some(Ordering::Equal) => Trend::Constant,
+                    None => Trend::Undefined,
+                },
+            })
+            .collect())
+    }
+
+    /// Splits an interval into the sub-intervals over which the polynomial is concave up or concave down, and finds its inflection points.
+    ///
+    /// The interval is split at the roots of the second derivative, and the sign of the second derivative is then checked within each piece.
+    /// Every root strictly inside the interval is reported as an `InflectionPoint`, with `sign_changes` telling whether the concavity really flips there.
+    /// Neighbouring pieces with the same concavity are joined, and pieces are never joined across `x = 0` if the polynomial has a negative exponent.
+    ///
+    /// If the second derivative is zero everywhere (as for a straight line), the concavity is `Concavity::Undefined`.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{Concavity, ConcavityInterval, InflectionPoint, Monomial, Polynomial};
+    ///
+    /// // x^3 - 3x^2 has an inflection point at x = 1.
+    /// let my_polynomial = Polynomial(vec![
+    ///     Monomial { c: 1.0, e: 3.0 },
+    ///     Monomial { c: -3.0, e: 2.0 },
+    /// ]);
+    /// let analysis = my_polynomial.concavity(-1.0, 3.0).unwrap();
+    /// assert_eq!(analysis.intervals, vec![
+    ///     ConcavityInterval { start: -1.0, end: 1.0, concavity: Concavity::ConcaveDown },
+    ///     ConcavityInterval { start: 1.0, end: 3.0, concavity: Concavity::ConcaveUp },
+    /// ]);
+    /// assert_eq!(analysis.inflection_points, vec![
+    ///     InflectionPoint { x: 1.0, y: -2.0, sign_changes: true },
+    /// ]);
+    ///
+    /// // The second derivative of x^4 is zero at x = 0, but it does not change sign.
+    /// let my_polynomial = Polynomial(vec![Monomial { c: 1.0, e: 4.0 }]);
+    /// let analysis = my_polynomial.concavity(-1.0, 1.0).unwrap();
+    /// assert_eq!(analysis.intervals, vec![
+    ///     ConcavityInterval { start: -1.0, end: 1.0, concavity: Concavity::ConcaveUp },
+    /// ]);
+    /// assert_eq!(analysis.inflection_points, vec![
+    ///     InflectionPoint { x: 0.0, y: 0.0, sign_changes: false },
+    /// ]);
+    /// ```
+    ///
+    /// While it is recommended to order the start and end x-values in ascending order, this function will automatically swap them if they are not.
+    ///
+    /// ## Errors
+    ///
+    /// If the second derivative has an exponent of x which is not an integer, its roots cannot be found and an error is returned.
+    pub fn concavity(&self, start: f64, end: f64) -> Result<ConcavityAnalysis, CalcError> {
+        // Validate the start and end x-values are in the correct order,
+        // and swap them if they are not.
+        let (start_x, end_x) = if start > end {
+            (end, start)
+        } else {
+            (start, end)
+        };
+
+        let function = self.simplified()?;
+        let second_derivative = function.nth_derivative(2)?;
+        let pieces = sign_pieces(&function, &second_derivative, start_x, end_x)?;
+
+        let inflection_points = pieces
+            .windows(2)
+            .filter(|pair| !pair[1].after_pole)
+            .map(|pair| {
+                let x = pair[1].start;
+                let sign_changes = matches!(
+                    (pair[0].sign, pair[1].sign),
+                    (Some(Ordering::Less), Some(Ordering::Greater))
+                        | (Some(Ordering::Greater), Some(Ordering::Less))
+                );
+                InflectionPoint {
+                    x,
+                    y: function.value(x),
+                    sign_changes,
+                }
+            })
+            .collect();
+
+        let intervals = merge_pieces(pieces)
+            .into_iter()
+            .map(|piece| ConcavityInterval {
+                start: piece.start,
+                end: piece.end,
+                concavity: match piece.sign {
+                    Some(Ordering::Greater) => Concavity::ConcaveUp,
+                    Some(Ordering::Less) => Concavity::ConcaveDown,
+                    Some(Ordering::Equal) | None => Concavity::Undefined,
+                },
+            })
+            .collect();
+
+        Ok(ConcavityAnalysis {
+            intervals,
+            inflection_points,
+        })
+    }
+
+    /// Finds the critical points of the polynomial within a closed interval, where its derivative is zero, and classifies each one.
+    ///
+    /// Each critical point is classified with the second derivative test: it is a local minimum if the second derivative is positive there, and a local maximum if it is negative.
+    /// If the second derivative is zero, the higher derivatives from `nth_derivative()` are tried in turn. If the first of them which is not zero is of even order, its sign decides as before; if it is of odd order, the point is a saddle.
+    /// The number of derivatives which vanish is known from the multiplicity of the critical point as a root of the derivative, which is the first derivative test in disguise: the derivative changes sign exactly when the multiplicity is odd.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{CriticalPoint, CriticalPointKind, Monomial, Polynomial};
+    ///
+    /// // x^3 - 3x has a local maximum at x = -1 and a local minimum at x = 1.
+    /// let my_polynomial = Polynomial(vec![
+    ///     Monomial { c: 1.0, e: 3.0 },
+    ///     Monomial { c: -3.0, e: 1.0 },
+    /// ]);
+    /// assert_eq!(my_polynomial.critical_points(-2.0, 2.0).unwrap(), vec![
+    ///     CriticalPoint { x: -1.0, y: 2.0, kind: CriticalPointKind::LocalMaximum },
+    ///     CriticalPoint { x: 1.0, y: -2.0, kind: CriticalPointKind::LocalMinimum },
+    /// ]);
+    ///
+    /// // The second derivative of x^3 is zero at x = 0, and the third is not, so it is a saddle.
+    /// let my_polynomial = Polynomial(vec![Monomial { c: 1.0, e: 3.0 }]);
+    /// assert_eq!(my_polynomial.critical_points(-1.0, 1.0).unwrap(), vec![
+    ///     CriticalPoint { x: 0.0, y: 0.0, kind: CriticalPointKind::Saddle },
+    /// ]);
+    /// ```
+    ///
+    /// A constant polynomial has no critical points, even though its derivative is zero everywhere.
+    ///
+    /// While it is recommended to order the start and end x-values in ascending order, this function will automatically swap them if they are not.
+    ///
+    /// ## Errors
+    ///
+    /// If the derivative has an exponent of x which is not an integer, its roots cannot be found and an error is returned.
+    pub fn critical_points(&self, start: f64, end: f64) -> Result<Vec<CriticalPoint>, CalcError> {
+        // Validate the start and end x-values are in the correct order,
+        // and swap them if they are not.
+        let (start_x, end_x) = if start > end {
+            (end, start)
+        } else {
+            (start, end)
+        };
+
+        let function = self.simplified()?;
+        let derivative = function.derivative()?;
+        if derivative.0.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut critical_points = vec![];
+        for root in derivative.real_roots(ROOT_TOLERANCE)? {
+            // Roots within tolerance of an end of the interval belong to it.
+            let x = if math_helpers::is_equal_within_tolerance_to(&root.x, &start_x) {
+                start_x
+            } else if math_helpers::is_equal_within_tolerance_to(&root.x, &end_x) {
+                end_x
+            } else if start_x < root.x && root.x < end_x {
+                root.x
+            } else {
+                continue;
+            };
+
+            // The first derivative after f' which is not zero is at most the
+            // (multiplicity + 1)th, as x is a root of f' of that multiplicity.
+            let last_order = root.multiplicity + 1;
+            let mut kind = CriticalPointKind::Saddle;
+            let mut current = derivative.clone();
+            for order in 2..=last_order {
+                current = current.derivative()?;
+                let value = current.value(x);
+                if order < last_order && math_helpers::is_equal_within_tolerance_to(&value, &0_f64)
+                {
+                    continue;
+                }
+                kind = if order % 2 == 1 {
+                    CriticalPointKind::Saddle
+                } else if value > 0_f64 {
+                    CriticalPointKind::LocalMinimum
+                } else {
+                    CriticalPointKind::LocalMaximum
+                };
+                break;
+            }
+
+            critical_points.push(CriticalPoint {
+                x,
+                y: function.value(x),
+                kind,
+            });
+        }
+        Ok(critical_points)
+    }
+
+    /// Finds the critical points of the polynomial within a closed interval, and its absolute maximum and minimum over the interval.
+    ///
+    /// The absolute maximum and minimum are found among the critical points and the two endpoints of the interval. If several points share the greatest or least value, the leftmost one is reported.
+    ///
+    /// If the polynomial has a negative exponent and the interval contains `x = 0`, the polynomial tends to positive or negative infinity there, and there is no absolute maximum or minimum respectively.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{Extremum, Monomial, Polynomial};
+    ///
+    /// // x^3 - 3x on [-2, 3]
+    /// let my_polynomial = Polynomial(vec![
+    ///     Monomial { c: 1.0, e: 3.0 },
+    ///     Monomial { c: -3.0, e: 1.0 },
+    /// ]);
+    /// let extrema = my_polynomial.extrema(-2.0, 3.0).unwrap();
+    /// assert_eq!(extrema.critical_points.len(), 2);
+    /// // The local minimum at x = 1 ties with the endpoint at x = -2.
+    /// assert_eq!(extrema.absolute_minimum, Some(Extremum { x: -2.0, y: -2.0 }));
+    /// assert_eq!(extrema.absolute_maximum, Some(Extremum { x: 3.0, y: 18.0 }));
+    ///
+    /// // 1/x^2 grows without bound at x = 0.
+    /// let my_polynomial = Polynomial(vec![Monomial { c: 1.0, e: -2.0 }]);
+    /// let extrema = my_polynomial.extrema(-1.0, 2.0).unwrap();
+    /// assert_eq!(extrema.absolute_maximum, None);
+    /// assert_eq!(extrema.absolute_minimum, Some(Extremum { x: 2.0, y: 0.25 }));
+    /// ```
+    ///
+    /// While it is recommended to order the start and end x-values in ascending order, this function will automatically swap them if they are not.
+    ///
+    /// ## Errors
+    ///
+    /// If the derivative has an exponent of x which is not an integer, its roots cannot be found and an error is returned.
+    pub fn extrema(&self, start: f64, end: f64) -> Result<Extrema, CalcError> {
+        // Validate the start and end x-values are in the correct order,
+        // and swap them if they are not.
+        let (start_x, end_x) = if start > end {
+            (end, start)
+        } else {
+            (start, end)
+        };
+
+        let function = self.simplified()?;
+        let critical_points = function.critical_points(start_x, end_x)?;
+
+        let mut candidates = vec![Extremum {
+            x: start_x,
+            y: function.value(start_x),
+        }];
+        candidates.extend(critical_points.iter().map(|point| Extremum {
+            x: point.x,
+            y: point.y,
+        }));
+        candidates.push(Extremum {
+            x: end_x,
+            y: function.value(end_x),
+        });
+        candidates.retain(|candidate| candidate.y.is_finite());
+
+        let mut absolute_maximum: Option<Extremum> = None;
+        let mut absolute_minimum: Option<Extremum> = None;
+        for candidate in candidates {
+            if absolute_maximum
+                .as_ref()
+                .is_none_or(|maximum| candidate.y > maximum.y)
+            {
+                absolute_maximum = Some(candidate.clone());
+            }
+            if absolute_minimum
+                .as_ref()
+                .is_none_or(|minimum| candidate.y < minimum.y)
+            {
+                absolute_minimum = Some(candidate);
+            }
+        }
+
+        // Near a singularity at 0, the term with the most negative exponent
+        // dominates, and its sign on each side decides which way it goes.
+        if has_pole(&function)
+            && start_x <= 0_f64
+            && 0_f64 <= end_x
+            && let Some(dominant) = function.0.last()
+        {
+            let right = dominant.c.signum();
+            let left = if dominant.e.rem_euclid(2_f64) == 0_f64 {
+                right
+            } else {
+                -right
+            };
+            let sides = [(start_x < 0_f64, left), (0_f64 < end_x, right)];
+            for (within_interval, sign) in sides {
+                if within_interval && sign > 0_f64 {
+                    absolute_maximum = None;
+                }
+                if within_interval && sign < 0_f64 {
+                    absolute_minimum = None;
+                }
+            }
+        }
+
+        Ok(Extrema {
+            critical_points,
+            absolute_maximum,
+            absolute_minimum,
+        })
+    }
+}
@@ -0,0 +1,243 @@
+//! Helpers for working with polynomials as vectors of coefficients.
+//!
+//! A coefficient vector stores the coefficient of `x^i` at index `i`, so `[1.0, -2.0, 3.0]` is `3x^2 - 2x + 1`.
+//! This representation only works for integer exponents, but it makes algorithms such as division and root finding much simpler.
+
+use crate::{
+    CalcError, DomainError, Monomial, Polynomial, math_helpers, multiplication, multipoint,
+};
+
+/// Converts a polynomial with integer exponents into a coefficient vector.
+///
+/// Negative exponents are handled by shifting every exponent up by the lowest one, which is returned alongside the coefficients.
+/// In other words, the polynomial equals `x^shift` times the polynomial described by the coefficients.
+pub(crate) fn from_polynomial(polynomial: &Polynomial) -> Result<(Vec<f64>, i32), CalcError> {
+    let mut exponents = Vec::with_capacity(polynomial.0.len());
+    for element in &polynomial.0 {
+        let rounded = element.e.round();
+        if !math_helpers::is_equal_within_tolerance_to(&element.e, &rounded) {
+            return Err(DomainError::NonIntegerExponent {
+                exponent: element.e,
+            }
+            .into());
+        }
+        if rounded.abs() > f64::from(i32::MAX) {
+            return Err(DomainError::DegreeTooLarge { degree: rounded }.into());
+        }
+        #[allow(clippy::cast_possible_truncation)]
+        exponents.push(rounded as i32);
+    }
+
+    let shift = exponents.iter().copied().min().unwrap_or(0);
+    let highest = exponents.iter().copied().max().unwrap_or(0);
+    let degree = checked_length(i64::from(highest) - i64::from(shift))?;
+    let mut coefficients = vec![0_f64; degree + 1];
+    for (element, exponent) in polynomial.0.iter().zip(exponents) {
+        coefficients[usize::try_from(i64::from(exponent) - i64::from(shift)).unwrap_or(0)] +=
+            element.c;
+    }
+    trim(&mut coefficients);
+    Ok((coefficients, shift))
+}
+
+/// The largest span of exponents that is converted into a coefficient vector, so that sparse polynomials like `x^1e9` fail instead of allocating gigabytes.
+const MAX_DEGREE: i64 = 1 << 24;
+
+/// Checks that a span of exponents is small enough to store a coefficient for each power of x in it.
+fn checked_length(degree: i64) -> Result<usize, CalcError> {
+    match usize::try_from(degree) {
+        Ok(length) if degree <= MAX_DEGREE => Ok(length),
+        #[allow(clippy::cast_precision_loss)]
+        _ => Err(DomainError::DegreeTooLarge {
+            degree: degree as f64,
+        }
+        .into()),
+    }
+}
+
+/// A sparse polynomial is only converted into a coefficient vector if that vector is at most this many times longer than its number of monomials.
+const MAX_SPARSITY: usize = 4;
+
+/// Whether the exponents of a polynomial span few enough powers of x for it to be worth storing as a coefficient vector.
+pub(crate) fn is_dense(polynomial: &Polynomial) -> bool {
+    let exponents = polynomial.0.iter().map(|element| element.e);
+    let lowest = exponents.clone().fold(f64::INFINITY, f64::min);
+    let highest = exponents.fold(f64::NEG_INFINITY, f64::max);
+    #[allow(clippy::cast_precision_loss)]
+    let limit = (MAX_SPARSITY * polynomial.0.len()) as f64;
+    highest - lowest < limit
+}
+
+/// Converts a polynomial with non-negative integer exponents into a coefficient vector, without any shift.
+pub(crate) fn from_non_negative_polynomial(polynomial: &Polynomial) -> Result<Vec<f64>, CalcError> {
+    let (coefficients, shift) = from_polynomial(polynomial)?;
+    if coefficients.is_empty() {
+        return Ok(coefficients);
+    }
+    let Ok(shift) = usize::try_from(shift) else {
+        return Err(DomainError::NegativeExponent {
+            exponent: f64::from(shift),
+        }
+        .into());
+    };
+    #[allow(clippy::cast_possible_wrap)]
+    checked_length((shift + coefficients.len() - 1) as i64)?;
+    let mut shifted = vec![0_f64; shift];
+    shifted.extend(coefficients);
+    Ok(shifted)
+}
+
+/// Converts a coefficient vector back into a simplified polynomial.
+pub(crate) fn to_polynomial(coefficients: &[f64]) -> Polynomial {
+    let mut elements = vec![];
+    for (exponent, c) in coefficients.iter().enumerate().rev() {
+        if *c != 0_f64 {
+            #[allow(clippy::cast_precision_loss)]
+            elements.push(Monomial::new(*c, exponent as f64));
+        }
+    }
+    Polynomial(elements)
+}
+
+/// Removes zero coefficients from the high-degree end, so that the last coefficient is the leading one.
+pub(crate) fn trim(coefficients: &mut Vec<f64>) {
+    while coefficients.last() == Some(&0_f64) {
+        coefficients.pop();
+    }
+}
+
+/// The degree of the polynomial, or `None` for the zero polynomial.
+pub(crate) fn degree(coefficients: &[f64]) -> Option<usize> {
+    coefficients.iter().rposition(|c| *c != 0_f64)
+}
+
+/// The largest absolute value of the coefficients, used to put tolerances on the same scale as the polynomial.
+pub(crate) fn scale(coefficients: &[f64]) -> f64 {
+    coefficients.iter().fold(0_f64, |max, c| max.max(c.abs()))
+}
+
+/// Evaluates the polynomial at x using Horner's method.
+pub(crate) fn evaluate(coefficients: &[f64], x: f64) -> f64 {
+    coefficients
+        .iter()
+        .rev()
+        .fold(0_f64, |value, c| value * x + c)
+}
+
+/// Evaluates the polynomial at many points, writing the values into `values`.
+///
+/// Polynomials of high degree are evaluated at many more points than they have coefficients by re-centring them, with the `multipoint` module.
+/// Otherwise, Horner's method is used, with `evaluate_blocks()`.
+pub(crate) fn evaluate_many(coefficients: &[f64], xs: &[f64], values: &mut [f64]) {
+    if coefficients.len() >= multipoint::MULTIPOINT_THRESHOLD
+        && xs.len() >= multipoint::POINTS_PER_COEFFICIENT * coefficients.len()
+    {
+        multipoint::evaluate(coefficients, xs, values);
+    } else {
+        evaluate_blocks(coefficients, xs, values);
+    }
+}
+
+/// Evaluates the polynomial at many points with Horner's method, writing the values into `values`.
+///
+/// The points are taken in blocks, so that the multiplications and additions for the points of a block do not wait on one another.
+pub(crate) fn evaluate_blocks(coefficients: &[f64], xs: &[f64], values: &mut [f64]) {
+    const BLOCK: usize = 8;
+    for (xs, values) in xs.chunks(BLOCK).zip(values.chunks_mut(BLOCK)) {
+        let mut block = [0_f64; BLOCK];
+        for c in coefficients.iter().rev() {
+            for (value, x) in block.iter_mut().zip(xs) {
+                *value = *value * x + c;
+            }
+        }
+        values.copy_from_slice(&block[..values.len()]);
+    }
+}
+
+/// Calculates the coefficients of the derivative.
+pub(crate) fn derivative(coefficients: &[f64]) -> Vec<f64> {
+    (1_u32..)
+        .zip(coefficients.iter().skip(1))
+        .map(|(exponent, c)| c * f64::from(exponent))
+        .collect()
+}
+
+/// Replaces the polynomial `p(x)` with `p(x + shift)`, by repeated synthetic division.
+pub(crate) fn taylor_shift(coefficients: &mut [f64], shift: f64) {
+    let n = coefficients.len();
+    for i in 1..n {
+        for j in (i..n).rev() {
+            coefficients[j - 1] += shift * coefficients[j];
+        }
+    }
+}
+
+/// Multiplies two polynomials, with an algorithm chosen by the `multiplication` module.
+pub(crate) fn multiply(a: &[f64], b: &[f64]) -> Vec<f64> {
+    multiplication::multiply(a, b)
+}
+
+/// Divides one polynomial by another, returning the quotient and the remainder.
+///
+/// Remainder coefficients which are zero within the tolerance of the `math_helpers` module (relative to the size of the dividend) are set to exactly zero, so that an exact-enough division leaves an empty remainder.
+///
+/// The divisor must not be the zero polynomial.
+pub(crate) fn div_rem(dividend: &[f64], divisor: &[f64]) -> (Vec<f64>, Vec<f64>) {
+    let mut remainder = dividend.to_vec();
+    trim(&mut remainder);
+    let mut divisor = divisor.to_vec();
+    trim(&mut divisor);
+    let Some(divisor_degree) = degree(&divisor) else {
+        return (vec![], remainder);
+    };
+    if remainder.len() <= divisor_degree {
+        return (vec![], remainder);
+    }
+
+    let dividend_scale = scale(&remainder);
+    let leading = divisor[divisor_degree];
+    let mut quotient = vec![0_f64; remainder.len() - divisor_degree];
+    for i in (0..quotient.len()).rev() {
+        let factor = remainder[i + divisor_degree] / leading;
+        quotient[i] = factor;
+        for (j, d) in divisor.iter().enumerate() {
+            remainder[i + j] -= factor * d;
+        }
+        remainder[i + divisor_degree] = 0_f64;
+    }
+
+    remainder.truncate(divisor_degree);
+    for r in &mut remainder {
+        if math_helpers::is_equal_within_tolerance_to(&(*r / dividend_scale), &0_f64) {
+            *r = 0_f64;
+        }
+    }
+    trim(&mut remainder);
+    (quotient, remainder)
+}
+
+/// Scales the polynomial so that its leading coefficient is `1`.
+pub(crate) fn monic(coefficients: &[f64]) -> Vec<f64> {
+    let mut coefficients = coefficients.to_vec();
+    trim(&mut coefficients);
+    if let Some(leading) = coefficients.last().copied() {
+        for c in &mut coefficients {
+            *c /= leading;
+        }
+    }
+    coefficients
+}
+
+/// Calculates the monic greatest common divisor of two polynomials with the Euclidean algorithm.
+///
+/// If both polynomials are zero, the zero polynomial is returned.
+pub(crate) fn gcd(a: &[f64], b: &[f64]) -> Vec<f64> {
+    let mut a = monic(a);
+    let mut b = monic(b);
+    while degree(&b).is_some() {
+        let (_, remainder) = div_rem(&a, &b);
+        a = b;
+        b = monic(&remainder);
+    }
+    a
+}
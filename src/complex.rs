@@ -0,0 +1,237 @@
+//! A minimal complex number type, used for the complex roots of polynomials.
+
+use serde::{Deserialize, Serialize};
+
+/// A complex number `re + im * i`.
+///
+/// #### Example
+/// ```rust
+/// use calcucalc::Complex;
+///
+/// let z = Complex::new(3.0, 4.0);
+/// assert_eq!(z.norm(), 5.0);
+/// assert_eq!(z * z.conj(), Complex::new(25.0, 0.0));
+/// assert_eq!(z.to_string(), "3 + 4i");
+/// ```
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, PartialOrd, Serialize)]
+#[must_use]
+pub struct Complex {
+    /// The <u>re</u>al part
+    pub re: f64,
+    /// The <u>im</u>aginary part
+    pub im: f64,
+}
+
+impl Complex {
+    /// The imaginary unit, `i`.
+    pub const I: Self = Self {
+        re: 0_f64,
+        im: 1_f64,
+    };
+
+    /// Creates a new complex number.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::Complex;
+    ///
+    /// let z = Complex::new(1.0, -2.0);
+    /// assert_eq!(z, Complex { re: 1.0, im: -2.0 });
+    /// ```
+    pub fn new(re: f64, im: f64) -> Self {
+        Self { re, im }
+    }
+
+    /// Creates a complex number from its polar form, `r * e^(i * theta)`.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::Complex;
+    ///
+    /// let z = Complex::from_polar(2.0, std::f64::consts::FRAC_PI_2);
+    /// assert!((z - Complex::new(0.0, 2.0)).norm() < 1e-15);
+    /// ```
+    pub fn from_polar(r: f64, theta: f64) -> Self {
+        Self {
+            re: r * theta.cos(),
+            im: r * theta.sin(),
+        }
+    }
+
+    /// The absolute value (or modulus) of the complex number.
+    #[must_use]
+    pub fn norm(&self) -> f64 {
+        self.re.hypot(self.im)
+    }
+
+    /// The complex conjugate, `re - im * i`.
+    pub fn conj(&self) -> Self {
+        Self {
+            re: self.re,
+            im: -self.im,
+        }
+    }
+
+    /// The principal value of the natural logarithm, whose imaginary part lies in `(-π, π]`.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::Complex;
+    ///
+    /// let z = Complex::new(-1.0, 0.0).ln();
+    /// assert_eq!(z, Complex::new(0.0, std::f64::consts::PI));
+    /// ```
+    pub fn ln(&self) -> Self {
+        Self {
+            re: self.norm().ln(),
+            im: self.im.atan2(self.re),
+        }
+    }
+
+    /// The exponential function, `e^z`.
+    pub fn exp(&self) -> Self {
+        Self::from_polar(self.re.exp(), self.im)
+    }
+
+    /// Raises the complex number to a complex power, using the principal value of the logarithm.
+    ///
+    /// Integer powers are calculated by repeated multiplication, so that they are exact where possible.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::Complex;
+    ///
+    /// let z = Complex::new(1.0, 1.0);
+    /// assert_eq!(z.powc(Complex::new(2.0, 0.0)), Complex::new(0.0, 2.0));
+    /// assert!((Complex::I.powc(Complex::I) - Complex::new((-std::f64::consts::FRAC_PI_2).exp(), 0.0)).norm() < 1e-15);
+    /// ```
+    pub fn powc(&self, exponent: Self) -> Self {
+        if exponent.im == 0_f64
+            && exponent.re.fract() == 0_f64
+            && exponent.re.abs() <= f64::from(i32::MAX)
+        {
+            #[allow(clippy::cast_possible_truncation)]
+            let n = exponent.re as i32;
+            let mut result = Self::from(1_f64);
+            let mut base = *self;
+            let mut remaining = n.unsigned_abs();
+            while remaining > 0 {
+                if remaining % 2 == 1 {
+                    result = result * base;
+                }
+                base = base * base;
+                remaining /= 2;
+            }
+            return if n < 0 {
+                Self::from(1_f64) / result
+            } else {
+                result
+            };
+        }
+        if self.re == 0_f64 && self.im == 0_f64 {
+            return if exponent.re > 0_f64 {
+                *self
+            } else {
+                Self::new(f64::INFINITY, 0_f64)
+            };
+        }
+        (exponent * self.ln()).exp()
+    }
+
+    /// Whether the imaginary part is zero within the tolerance of the `math_helpers` module.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::Complex;
+    ///
+    /// assert!(Complex::new(2.0, 1e-12).is_real());
+    /// assert!(!Complex::I.is_real());
+    /// ```
+    #[must_use]
+    pub fn is_real(&self) -> bool {
+        crate::math_helpers::is_equal_within_tolerance_to(&self.im, &0_f64)
+    }
+}
+
+impl From<f64> for Complex {
+    fn from(re: f64) -> Self {
+        Self { re, im: 0_f64 }
+    }
+}
+
+impl std::fmt::Display for Complex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.im < 0_f64 {
+            write!(f, "{} - {}i", self.re, -self.im)
+        } else {
+            write!(f, "{} + {}i", self.re, self.im)
+        }
+    }
+}
+
+impl std::ops::Add for Complex {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            re: self.re + other.re,
+            im: self.im + other.im,
+        }
+    }
+}
+
+impl std::ops::Sub for Complex {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            re: self.re - other.re,
+            im: self.im - other.im,
+        }
+    }
+}
+
+impl std::ops::Mul for Complex {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self {
+            re: self.re * other.re - self.im * other.im,
+            im: self.re * other.im + self.im * other.re,
+        }
+    }
+}
+
+impl std::ops::Mul<f64> for Complex {
+    type Output = Self;
+
+    fn mul(self, other: f64) -> Self {
+        Self {
+            re: self.re * other,
+            im: self.im * other,
+        }
+    }
+}
+
+impl std::ops::Div for Complex {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        let denominator = other.re * other.re + other.im * other.im;
+        Self {
+            re: (self.re * other.re + self.im * other.im) / denominator,
+            im: (self.im * other.re - self.re * other.im) / denominator,
+        }
+    }
+}
+
+impl std::ops::Neg for Complex {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            re: -self.re,
+            im: -self.im,
+        }
+    }
+}
@@ -0,0 +1,233 @@
+//! Dense polynomials, which store a coefficient for every power of x up to their degree.
+//!
+//! `Polynomial` can hold any exponents, so evaluating it raises x to the power of each exponent in turn.
+//! When every exponent is a non-negative integer, a `DensePolynomial` can be evaluated with Horner's method instead, using one multiplication and one addition per coefficient.
+//!
+//! #### Example
+//! ```rust
+//! use calcucalc::{DensePolynomial, Monomial, Polynomial};
+//!
+//! let my_polynomial = Polynomial(vec![
+//!     Monomial { c: 3.0, e: 2.0 },
+//!     Monomial { c: -4.0, e: 1.0 },
+//!     Monomial { c: 1.0, e: 0.0 },
+//! ]);
+//! let my_dense_polynomial = DensePolynomial::try_from(&my_polynomial).unwrap();
+//! assert_eq!(my_dense_polynomial, DensePolynomial(vec![1.0, -4.0, 3.0]));
+//! assert_eq!(my_dense_polynomial.value(2.0), my_polynomial.value(2.0));
+//! assert_eq!(Polynomial::from(my_dense_polynomial), my_polynomial);
+//! ```
+
+use std::ops::Mul;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{CalcError, Polynomial, coefficients};
+
+/// A polynomial with non-negative integer exponents, stored as a vector whose element at index `i` is the coefficient of `x^i`.
+///
+/// For example, `3x^2 - 4x + 1` is `DensePolynomial(vec![1.0, -4.0, 3.0])`.
+///
+/// #### Example
+/// ```rust
+/// use calcucalc::DensePolynomial;
+///
+/// let my_dense_polynomial = DensePolynomial(vec![1.0, -4.0, 3.0]);
+/// assert_eq!(my_dense_polynomial.value(2.0), 5.0);
+/// assert_eq!(my_dense_polynomial.degree(), Some(2));
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[must_use]
+pub struct DensePolynomial(pub Vec<f64>);
+
+impl DensePolynomial {
+    /// Calculates the value of the polynomial for a given value of x, with Horner's method.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::DensePolynomial;
+    ///
+    /// // x^3 - 2x + 5
+    /// let my_dense_polynomial = DensePolynomial(vec![5.0, -2.0, 0.0, 1.0]);
+    /// assert_eq!(my_dense_polynomial.value(3.0), 26.0);
+    /// assert_eq!(DensePolynomial(vec![]).value(3.0), 0.0);
+    /// ```
+    #[must_use]
+    pub fn value(&self, x: f64) -> f64 {
+        coefficients::evaluate(&self.0, x)
+    }
+
+    /// Calculates the values of the polynomial for many values of x at once, like `evaluate_into()`.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::DensePolynomial;
+    ///
+    /// // x^2 - 1
+    /// let my_dense_polynomial = DensePolynomial(vec![-1.0, 0.0, 1.0]);
+    /// assert_eq!(my_dense_polynomial.values(&[-2.0, 0.0, 3.0]), vec![3.0, -1.0, 8.0]);
+    /// ```
+    #[must_use]
+    pub fn values(&self, xs: &[f64]) -> Vec<f64> {
+        let mut values = vec![0_f64; xs.len()];
+        self.evaluate_into(xs, &mut values);
+        values
+    }
+
+    /// Calculates the values of the polynomial for many values of x at once, writing them into `values`.
+    ///
+    /// The values for several points are calculated side by side, which is faster than calling `value()` for each of them.
+    /// From 256 coefficients, with at least four points per coefficient, the points are sorted into groups of neighbours instead,
+    /// and the polynomial is re-centred at each group so that only the terms which matter near it need to be evaluated.
+    /// The values then differ from those of `value()` by rounding, within the accuracy of Horner's method at the point of each group farthest from zero.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::DensePolynomial;
+    ///
+    /// let my_dense_polynomial = DensePolynomial(vec![-1.0, 0.0, 1.0]);
+    /// let mut values = [0.0; 3];
+    /// my_dense_polynomial.evaluate_into(&[-2.0, 0.0, 3.0], &mut values);
+    /// assert_eq!(values, [3.0, -1.0, 8.0]);
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `xs` and `values` have different lengths.
+    pub fn evaluate_into(&self, xs: &[f64], values: &mut [f64]) {
+        assert_eq!(
+            xs.len(),
+            values.len(),
+            "xs and values must have the same length"
+        );
+        coefficients::evaluate_many(&self.0, xs, values);
+    }
+
+    /// The degree of the polynomial, which is the highest power of x with a non-zero coefficient, or `None` for the zero polynomial.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::DensePolynomial;
+    ///
+    /// assert_eq!(DensePolynomial(vec![1.0, 2.0, 0.0]).degree(), Some(1));
+    /// assert_eq!(DensePolynomial(vec![0.0]).degree(), None);
+    /// ```
+    #[must_use]
+    pub fn degree(&self) -> Option<usize> {
+        coefficients::degree(&self.0)
+    }
+
+    /// Calculates the derivative of the polynomial.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::DensePolynomial;
+    ///
+    /// // d/dx (3x^2 - 4x + 1) = 6x - 4
+    /// let my_dense_polynomial = DensePolynomial(vec![1.0, -4.0, 3.0]);
+    /// assert_eq!(my_dense_polynomial.derivative(), DensePolynomial(vec![-4.0, 6.0]));
+    /// ```
+    pub fn derivative(&self) -> Self {
+        Self(coefficients::derivative(&self.0))
+    }
+
+    /// Multiplies one dense polynomial by another.
+    ///
+    /// The algorithm is chosen from the number of coefficients: every pair of coefficients is multiplied for small polynomials,
+    /// Karatsuba's algorithm is used from 32 coefficients, and multiplication with the fast Fourier transform from 4096.
+    /// The fast Fourier transform rounds every coefficient of the product to within a small multiple of `f64::EPSILON` times the largest one.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::DensePolynomial;
+    ///
+    /// // (x + 1)(x - 1) = x^2 - 1
+    /// let my_product = DensePolynomial(vec![1.0, 1.0]).multiply_polynomial(&DensePolynomial(vec![-1.0, 1.0]));
+    /// assert_eq!(my_product, DensePolynomial(vec![-1.0, 0.0, 1.0]));
+    ///
+    /// // (1 + x + ... + x^999)^2 has the coefficients 1, 2, ..., 1000, ..., 2, 1
+    /// let my_dense_polynomial = DensePolynomial(vec![1.0; 1000]);
+    /// let my_product = &my_dense_polynomial * &my_dense_polynomial;
+    /// assert_eq!(my_product.0.len(), 1999);
+    /// assert_eq!(my_product.0[999], 1000.0);
+    /// ```
+    pub fn multiply_polynomial(&self, other: &Self) -> Self {
+        Self(coefficients::multiply(&self.0, &other.0))
+    }
+}
+
+/// Multiplies two dense polynomials, like `multiply_polynomial()`.
+impl Mul for &DensePolynomial {
+    type Output = DensePolynomial;
+
+    fn mul(self, rhs: Self) -> DensePolynomial {
+        self.multiply_polynomial(rhs)
+    }
+}
+
+/// Multiplies two dense polynomials, like `multiply_polynomial()`.
+impl Mul for DensePolynomial {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.multiply_polynomial(&rhs)
+    }
+}
+
+/// Converts a polynomial with non-negative integer exponents into a dense polynomial.
+///
+/// Monomials with the same exponent are added together, and exponents within the crate-wide default `Tolerance` of an integer are rounded to it.
+///
+/// #### Example
+/// ```rust
+/// use calcucalc::{CalcError, DensePolynomial, DomainError, Monomial, Polynomial};
+///
+/// let my_polynomial = Polynomial(vec![
+///     Monomial { c: 1.0, e: 3.0 },
+///     Monomial { c: 2.0, e: 0.0 },
+///     Monomial { c: 4.0, e: 3.0 },
+/// ]);
+/// assert_eq!(DensePolynomial::try_from(&my_polynomial), Ok(DensePolynomial(vec![2.0, 0.0, 0.0, 5.0])));
+///
+/// let my_polynomial = Polynomial(vec![Monomial { c: 1.0, e: 0.5 }]);
+/// assert_eq!(
+///     DensePolynomial::try_from(&my_polynomial),
+///     Err(CalcError::Domain(DomainError::NonIntegerExponent { exponent: 0.5 })),
+/// );
+/// ```
+///
+/// ## Errors
+///
+/// If the polynomial has an exponent of x which is not a non-negative integer, an error is returned.
+impl TryFrom<&Polynomial> for DensePolynomial {
+    type Error = CalcError;
+
+    fn try_from(polynomial: &Polynomial) -> Result<Self, Self::Error> {
+        Ok(Self(coefficients::from_non_negative_polynomial(
+            polynomial,
+        )?))
+    }
+}
+
+/// Converts a polynomial with non-negative integer exponents into a dense polynomial, like `DensePolynomial::try_from(&Polynomial)`.
+impl TryFrom<Polynomial> for DensePolynomial {
+    type Error = CalcError;
+
+    fn try_from(polynomial: Polynomial) -> Result<Self, Self::Error> {
+        Self::try_from(&polynomial)
+    }
+}
+
+/// Converts a dense polynomial into a polynomial in canonical form, leaving out the coefficients of `0`.
+impl From<&DensePolynomial> for Polynomial {
+    fn from(dense_polynomial: &DensePolynomial) -> Self {
+        coefficients::to_polynomial(&dense_polynomial.0)
+    }
+}
+
+/// Converts a dense polynomial into a polynomial in canonical form, like `Polynomial::from(&DensePolynomial)`.
+impl From<DensePolynomial> for Polynomial {
+    fn from(dense_polynomial: DensePolynomial) -> Self {
+        Self::from(&dense_polynomial)
+    }
+}
@@ -0,0 +1,282 @@
+//! Human-readable formatting of monomials and polynomials, e.g. `3x^2 - 4x + 1`.
+//!
+//! The precision of the formatter (as in `{:.2}`) is applied to coefficients, and to exponents which are not whole numbers.
+//! Polynomials with `Rational` coefficients and exponents are written with their fractions in brackets, e.g. `(1/3)x^3 + x^(-2/3)`.
+
+use std::fmt;
+
+use crate::{Antiderivative, Monomial, Polynomial, Rational, Scalar};
+
+/// A number which can be written as part of a term.
+trait Number: Scalar + Copy {
+    /// Whether the number is a whole number.
+    fn is_integer(self) -> bool;
+
+    /// Whether the number should be written with a minus sign.
+    fn is_written_negative(self) -> bool;
+
+    /// Writes the number on its own, as a constant term.
+    fn write_constant(self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+
+    /// Writes the number in front of `x` or `ln|x|`.
+    fn write_coefficient(self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_constant(f)
+    }
+
+    /// Writes the number after `x^`.
+    fn write_exponent(self, f: &mut fmt::Formatter<'_>) -> fmt::Result;
+}
+
+impl Number for f64 {
+    fn is_integer(self) -> bool {
+        self.fract() == 0_f64
+    }
+
+    fn is_written_negative(self) -> bool {
+        self.is_sign_negative()
+    }
+
+    fn write_constant(self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match f.precision() {
+            Some(precision) => write!(f, "{self:.precision$}"),
+            None => write!(f, "{self}"),
+        }
+    }
+
+    fn write_exponent(self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_integer() {
+            write!(f, "{self}")
+        } else {
+            self.write_constant(f)
+        }
+    }
+}
+
+impl Number for Rational {
+    fn is_integer(self) -> bool {
+        Self::is_integer(&self)
+    }
+
+    fn is_written_negative(self) -> bool {
+        self < Self::ZERO
+    }
+
+    fn write_constant(self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self}")
+    }
+
+    fn write_coefficient(self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if Number::is_integer(self) {
+            write!(f, "{self}")
+        } else {
+            write!(f, "({self})")
+        }
+    }
+
+    fn write_exponent(self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_coefficient(f)
+    }
+}
+
+/// A single term of a sum being formatted.
+enum Term<T> {
+    /// `c * x^e`
+    Power { c: T, e: T },
+    /// `c * ln|x|`
+    Log { c: T },
+}
+
+impl<T: Number> Term<T> {
+    fn coefficient(&self) -> T {
+        match self {
+            Self::Power { c, .. } | Self::Log { c } => *c,
+        }
+    }
+
+    /// Writes the term without the sign of its coefficient, so that the caller can place it.
+    ///
+    /// Coefficients and exponents are compared exactly, because the output must not hide a difference from `1`.
+    fn write_unsigned(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = self.coefficient();
+        let c = if c.is_written_negative() { -c } else { c };
+        match *self {
+            Self::Power { e, .. } => {
+                if e == T::zero() {
+                    return c.write_constant(f);
+                }
+                if c != T::one() {
+                    c.write_coefficient(f)?;
+                }
+                write!(f, "x")?;
+                if e == T::one() {
+                    Ok(())
+                } else {
+                    write!(f, "^")?;
+                    e.write_exponent(f)
+                }
+            }
+            Self::Log { .. } => {
+                if c != T::one() {
+                    c.write_coefficient(f)?;
+                }
+                write!(f, "ln|x|")
+            }
+        }
+    }
+}
+
+impl<T: Number> From<&Monomial<T>> for Term<T> {
+    fn from(monomial: &Monomial<T>) -> Self {
+        Self::Power {
+            c: monomial.c,
+            e: monomial.e,
+        }
+    }
+}
+
+/// Writes a sum of terms, such as `3x^2 - 4x + 1`, leaving out terms with a coefficient of `0`.
+fn write_terms<T: Number>(
+    f: &mut fmt::Formatter<'_>,
+    terms: impl IntoIterator<Item = Term<T>>,
+) -> fmt::Result {
+    let mut first = true;
+    for term in terms {
+        let c = term.coefficient();
+        if c == T::zero() {
+            continue;
+        }
+        match (first, c.is_written_negative()) {
+            (true, true) => write!(f, "-")?,
+            (true, false) => {}
+            (false, true) => write!(f, " - ")?,
+            (false, false) => write!(f, " + ")?,
+        }
+        term.write_unsigned(f)?;
+        first = false;
+    }
+    if first {
+        T::zero().write_constant(f)?;
+    }
+    Ok(())
+}
+
+/// Writes an antiderivative, placing the `ln|x|` term between the positive and the non-positive powers of x.
+fn write_antiderivative<T: Number>(
+    f: &mut fmt::Formatter<'_>,
+    antiderivative: &Antiderivative<T>,
+) -> fmt::Result {
+    let elements = &antiderivative.polynomial.0;
+    let non_positive_start = elements
+        .iter()
+        .position(|element| element.e <= T::zero())
+        .unwrap_or(elements.len());
+    let terms = elements[..non_positive_start]
+        .iter()
+        .map(Term::from)
+        .chain([Term::Log {
+            c: antiderivative.log_coefficient,
+        }])
+        .chain(elements[non_positive_start..].iter().map(Term::from));
+    write_terms(f, terms)
+}
+
+/// Formats the monomial as, for example, `3x^2`, `-x`, `0.5x^-1` or `7`.
+///
+/// #### Example
+/// ```rust
+/// use calcucalc::Monomial;
+///
+/// assert_eq!(Monomial { c: 3.0, e: 2.0 }.to_string(), "3x^2");
+/// assert_eq!(Monomial { c: -1.0, e: 1.0 }.to_string(), "-x");
+/// assert_eq!(Monomial { c: 5.0, e: -1.0 }.to_string(), "5x^-1");
+/// assert_eq!(Monomial { c: 2.0, e: 0.5 }.to_string(), "2x^0.5");
+/// assert_eq!(Monomial { c: 7.0, e: 0.0 }.to_string(), "7");
+/// assert_eq!(Monomial { c: 0.0, e: 4.0 }.to_string(), "0");
+/// assert_eq!(format!("{:.2}", Monomial { c: 1.0 / 3.0, e: 2.0 }), "0.33x^2");
+/// ```
+impl fmt::Display for Monomial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_terms(f, [Term::from(self)])
+    }
+}
+
+/// Formats the polynomial as, for example, `3x^2 - 4x + 1`.
+///
+/// The monomials are written in the order they are stored in, and monomials with a coefficient of `0` are left out.
+/// Call `simplified()` first to combine and sort them.
+///
+/// #### Example
+/// ```rust
+/// use calcucalc::{Monomial, Polynomial};
+///
+/// let my_polynomial = Polynomial(vec![
+///     Monomial { c: 3.0, e: 2.0 },
+///     Monomial { c: -4.0, e: 1.0 },
+///     Monomial { c: 1.0, e: 0.0 },
+/// ]);
+/// assert_eq!(my_polynomial.to_string(), "3x^2 - 4x + 1");
+///
+/// let my_polynomial = Polynomial(vec![
+///     Monomial { c: -1.0, e: 0.5 },
+///     Monomial { c: 0.0, e: 1.0 },
+///     Monomial { c: 2.0 / 3.0, e: -2.0 },
+/// ]);
+/// assert_eq!(format!("{my_polynomial:.3}"), "-x^0.500 + 0.667x^-2");
+///
+/// assert_eq!(Polynomial::new().to_string(), "0");
+/// ```
+impl fmt::Display for Polynomial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_terms(f, self.0.iter().map(Term::from))
+    }
+}
+
+/// Formats the antiderivative as, for example, `x^2 + 3ln|x| + 5`.
+///
+/// The `ln|x|` term is written between the positive and the non-positive powers of x.
+///
+/// #### Example
+/// ```rust
+/// use calcucalc::{Monomial, Polynomial};
+///
+/// let f = Polynomial(vec![
+///     Monomial { c: 2.0, e: 1.0 },
+///     Monomial { c: -3.0, e: -1.0 },
+/// ]);
+/// assert_eq!(f.antiderivative(5.0).unwrap().to_string(), "x^2 - 3ln|x| + 5");
+/// ```
+impl fmt::Display for Antiderivative {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_antiderivative(f, self)
+    }
+}
+
+/// Formats the monomial with its fractions in brackets, as for example `(1/3)x^3`, `-x^(2/3)` or `1/3`.
+///
+/// #### Example
+/// ```rust
+/// use calcucalc::{Monomial, Rational};
+///
+/// assert_eq!(Monomial::new(Rational::new(1, 3), Rational::from(3)).to_string(), "(1/3)x^3");
+/// assert_eq!(Monomial::new(Rational::from(-1), Rational::new(2, 3)).to_string(), "-x^(2/3)");
+/// assert_eq!(Monomial::new(Rational::new(1, 3), Rational::from(0)).to_string(), "1/3");
+/// ```
+impl fmt::Display for Monomial<Rational> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_terms(f, [Term::from(self)])
+    }
+}
+
+/// Formats the polynomial with its fractions in brackets, as for example `(1/3)x^3 - x^(-2/3) + 1/2`.
+impl fmt::Display for Polynomial<Rational> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_terms(f, self.0.iter().map(Term::from))
+    }
+}
+
+/// Formats the antiderivative with its fractions in brackets, as for example `(1/2)x^2 + (1/3)ln|x|`.
+impl fmt::Display for Antiderivative<Rational> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write_antiderivative(f, self)
+    }
+}
@@ -0,0 +1,176 @@
+//! The error type shared by every fallible operation in this library.
+
+use serde::{Deserialize, Serialize};
+
+use crate::ParseError;
+
+/// An error returned by a calculation.
+///
+/// #### Example
+/// ```rust
+/// use calcucalc::{CalcError, Monomial};
+///
+/// let m1 = Monomial { c: 1.0, e: 2.0 };
+/// let m2 = Monomial { c: 1.0, e: 3.0 };
+/// match m1.add_monomial_of_same_power(&m2) {
+///     Err(CalcError::MismatchedExponents { left, right }) => {
+///         assert_eq!((left, right), (2.0, 3.0));
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub enum CalcError {
+    /// Two monomials with different exponents of x were combined as if they had the same one.
+    MismatchedExponents {
+        /// The exponent of the left-hand monomial.
+        left: f64,
+        /// The exponent of the right-hand monomial.
+        right: f64,
+    },
+    /// The operation is not defined for its input.
+    Domain(DomainError),
+    /// A calculation produced an infinite or `NaN` value.
+    NonFinite(f64),
+    /// A value is too large to be represented exactly as a `Rational`.
+    Overflow(f64),
+    /// A string could not be parsed.
+    Parse(ParseError),
+}
+
+/// The ways in which the input of an operation can lie outside of its domain.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub enum DomainError {
+    /// The power rule cannot integrate `x^-1`, whose antiderivative is `ln|x|`.
+    LogarithmicTerm {
+        /// The coefficient of the `x^-1` term.
+        coefficient: f64,
+    },
+    /// A term has a singularity inside the interval of integration, and its improper integral does not converge.
+    DivergentIntegral {
+        /// The exponent of the offending term.
+        exponent: f64,
+        /// The x-value of the singularity.
+        singularity: f64,
+    },
+    /// A term with a fractional exponent would be evaluated for negative x, where it is not real (e.g. `x^0.5`).
+    FractionalPowerOfNegative {
+        /// The exponent of the offending term.
+        exponent: f64,
+    },
+    /// A power of an exact fraction is not itself a fraction, as for `2^(1/2)` or `0^-1`.
+    NonRationalPower {
+        /// The number being raised to a power.
+        base: f64,
+        /// The power it is raised to.
+        exponent: f64,
+    },
+    /// The operation requires integer exponents of x.
+    NonIntegerExponent {
+        /// The offending exponent.
+        exponent: f64,
+    },
+    /// The operation requires non-negative exponents of x.
+    NegativeExponent {
+        /// The offending exponent.
+        exponent: f64,
+    },
+    /// The exponents of x span too many powers to store a coefficient for each of them.
+    DegreeTooLarge {
+        /// The difference between the highest and lowest exponents, or an exponent which is too large on its own.
+        degree: f64,
+    },
+    /// The zero polynomial has infinitely many roots.
+    InfinitelyManyRoots,
+    /// Division by the zero polynomial.
+    DivisionByZero,
+    /// A tolerance must be a positive number.
+    InvalidTolerance(f64),
+}
+
+impl std::fmt::Display for CalcError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MismatchedExponents { left, right } => write!(
+                f,
+                "Cannot add monomials with different powers of x (x^{left} and x^{right})."
+            ),
+            Self::Domain(error) => write!(f, "{error}"),
+            Self::NonFinite(value) => write!(f, "The calculation evaluated to {value}."),
+            Self::Overflow(value) => {
+                write!(f, "{value} is too large to be represented as a fraction.")
+            }
+            Self::Parse(error) => write!(f, "Could not parse the input: {error}."),
+        }
+    }
+}
+
+impl std::fmt::Display for DomainError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::LogarithmicTerm { coefficient } => write!(
+                f,
+                "Cannot integrate {coefficient}x^-1 with the power rule; its antiderivative is {coefficient}ln|x|."
+            ),
+            Self::DivergentIntegral {
+                exponent,
+                singularity,
+            } => write!(
+                f,
+                "The integral of x^{exponent} diverges at the singularity x = {singularity}."
+            ),
+            Self::FractionalPowerOfNegative { exponent } => {
+                write!(f, "x^{exponent} is not real for negative x.")
+            }
+            Self::NonRationalPower { base, exponent } => {
+                write!(f, "{base}^({exponent}) is not a rational number.")
+            }
+            Self::NonIntegerExponent { exponent } => {
+                write!(
+                    f,
+                    "Expected integer exponents of x, but found x^{exponent}."
+                )
+            }
+            Self::NegativeExponent { exponent } => write!(
+                f,
+                "Expected non-negative exponents of x, but found x^{exponent}."
+            ),
+            Self::DegreeTooLarge { degree } => write!(
+                f,
+                "The exponents of x span {degree} powers, which is too many to store as coefficients."
+            ),
+            Self::InfinitelyManyRoots => {
+                write!(f, "The zero polynomial has infinitely many roots.")
+            }
+            Self::DivisionByZero => write!(f, "Cannot divide by the zero polynomial."),
+            Self::InvalidTolerance(tolerance) => write!(
+                f,
+                "The tolerance must be a positive number, but was {tolerance}."
+            ),
+        }
+    }
+}
+
+impl std::error::Error for CalcError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Domain(error) => Some(error),
+            Self::Parse(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl std::error::Error for DomainError {}
+
+impl From<DomainError> for CalcError {
+    fn from(error: DomainError) -> Self {
+        Self::Domain(error)
+    }
+}
+
+impl From<ParseError> for CalcError {
+    fn from(error: ParseError) -> Self {
+        Self::Parse(error)
+    }
+}
@@ -0,0 +1,488 @@
+//! Symbolic expressions, for functions of x which are not polynomials, such as `sin(x) / x` or `e^(2x)`.
+//!
+//! An `Expr` is a tree of constants, the variable x, arithmetic operators and the functions `sin`, `cos`, `tan`, `exp` and `ln`.
+//! Monomials and polynomials can be converted into expressions without losing any information, as `c * x^e` and sums of these.
+//!
+//! #### Example
+//! ```rust
+//! use calcucalc::{Expr, Monomial, Polynomial};
+//!
+//! // sin(x) * (3x^2 + 1)
+//! let my_polynomial = Polynomial(vec![
+//!     Monomial { c: 3.0, e: 2.0 },
+//!     Monomial { c: 1.0, e: 0.0 },
+//! ]);
+//! let my_expression = Expr::X.sin() * Expr::from(&my_polynomial);
+//! assert_eq!(my_expression.to_string(), "sin(x) * (3 * x^2 + 1 * x^0)");
+//! assert_eq!(my_expression.value(0.0), 0.0);
+//! assert_eq!(my_expression.value(2.0), 2.0_f64.sin() * 13.0);
+//! ```
+
+use std::fmt;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Monomial, Polynomial, simplify};
+
+/// A symbolic expression in the variable x.
+///
+/// Expressions are usually built from `Expr::X`, numbers, monomials and polynomials, with the arithmetic operators and the function methods such as `sin()`.
+///
+/// #### Example
+/// ```rust
+/// use calcucalc::Expr;
+///
+/// // e^(2x) - ln(x)
+/// let my_expression = (Expr::from(2.0) * Expr::X).exp() - Expr::X.ln();
+/// assert_eq!(my_expression.to_string(), "exp(2 * x) - ln(x)");
+/// assert_eq!(my_expression.value(1.0), 2.0_f64.exp());
+/// ```
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+#[must_use]
+pub enum Expr {
+    /// A number.
+    Constant(f64),
+    /// The variable x.
+    X,
+    /// The sum of two expressions.
+    Add(Box<Expr>, Box<Expr>),
+    /// The difference of two expressions.
+    Sub(Box<Expr>, Box<Expr>),
+    /// The product of two expressions.
+    Mul(Box<Expr>, Box<Expr>),
+    /// The quotient of two expressions.
+    Div(Box<Expr>, Box<Expr>),
+    /// The first expression raised to the power of the second.
+    Pow(Box<Expr>, Box<Expr>),
+    /// The sine of an expression, in radians.
+    Sin(Box<Expr>),
+    /// The cosine of an expression, in radians.
+    Cos(Box<Expr>),
+    /// The tangent of an expression, in radians.
+    Tan(Box<Expr>),
+    /// `e` raised to the power of an expression.
+    Exp(Box<Expr>),
+    /// The natural logarithm of an expression.
+    Ln(Box<Expr>),
+}
+
+impl Expr {
+    /// Raises the expression to the power of another.
+    pub fn pow(self, exponent: Self) -> Self {
+        Self::Pow(Box::new(self), Box::new(exponent))
+    }
+
+    /// The sine of the expression.
+    pub fn sin(self) -> Self {
+        Self::Sin(Box::new(self))
+    }
+
+    /// The cosine of the expression.
+    pub fn cos(self) -> Self {
+        Self::Cos(Box::new(self))
+    }
+
+    /// The tangent of the expression.
+    pub fn tan(self) -> Self {
+        Self::Tan(Box::new(self))
+    }
+
+    /// `e` raised to the power of the expression.
+    pub fn exp(self) -> Self {
+        Self::Exp(Box::new(self))
+    }
+
+    /// The natural logarithm of the expression.
+    pub fn ln(self) -> Self {
+        Self::Ln(Box::new(self))
+    }
+
+    /// Calculates the value of the expression for a given value of x.
+    ///
+    /// Like the operations on `f64` it is built from, this gives `NaN` or an infinity outside of the domain of the expression, as for `ln(x)` at `x = -1`.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::Expr;
+    ///
+    /// // x^2 / cos(x)
+    /// let my_expression = Expr::X.pow(Expr::from(2.0)) / Expr::X.cos();
+    /// assert_eq!(my_expression.value(0.0), 0.0);
+    /// assert_eq!(my_expression.value(1.0), 1.0 / 1.0_f64.cos());
+    /// assert!(Expr::X.ln().value(-1.0).is_nan());
+    /// ```
+    #[must_use]
+    pub fn value(&self, x: f64) -> f64 {
+        match self {
+            Self::Constant(c) => *c,
+            Self::X => x,
+            Self::Add(a, b) => a.value(x) + b.value(x),
+            Self::Sub(a, b) => a.value(x) - b.value(x),
+            Self::Mul(a, b) => a.value(x) * b.value(x),
+            Self::Div(a, b) => a.value(x) / b.value(x),
+            Self::Pow(base, exponent) => base.value(x).powf(exponent.value(x)),
+            Self::Sin(a) => a.value(x).sin(),
+            Self::Cos(a) => a.value(x).cos(),
+            Self::Tan(a) => a.value(x).tan(),
+            Self::Exp(a) => a.value(x).exp(),
+            Self::Ln(a) => a.value(x).ln(),
+        }
+    }
+
+    /// Checks if the expression is constant, which is when it does not contain x.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::Expr;
+    ///
+    /// assert!(Expr::from(2.0).ln().is_constant());
+    /// assert!(!(Expr::from(2.0) * Expr::X).is_constant());
+    /// ```
+    #[must_use]
+    pub fn is_constant(&self) -> bool {
+        match self {
+            Self::Constant(_) => true,
+            Self::X => false,
+            Self::Add(a, b)
+            | Self::Sub(a, b)
+            | Self::Mul(a, b)
+            | Self::Div(a, b)
+            | Self::Pow(a, b) => a.is_constant() && b.is_constant(),
+            Self::Sin(a) | Self::Cos(a) | Self::Tan(a) | Self::Exp(a) | Self::Ln(a) => {
+                a.is_constant()
+            }
+        }
+    }
+
+    /// Calculates the derivative of the expression symbolically, with the sum, product, quotient, power and chain rules.
+    ///
+    /// The result is not simplified, apart from leaving out the terms which the rules would multiply by the derivative of a constant.
+    /// For a polynomial, it has the same value as the derivative of the polynomial.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::Expr;
+    ///
+    /// // d/dx (sin(3x^2) * e^x) = cos(3x^2) * 6x * e^x + sin(3x^2) * e^x
+    /// let my_expression = (Expr::from(3.0) * Expr::X.pow(Expr::from(2.0))).sin() * Expr::X.exp();
+    /// let my_derivative = my_expression.derivative();
+    /// assert_eq!(
+    ///     my_derivative.to_string(),
+    ///     "cos(3 * x^2) * (3 * (2 * x^1)) * exp(x) + sin(3 * x^2) * exp(x)",
+    /// );
+    /// let x = 0.5_f64;
+    /// assert_eq!(
+    ///     my_derivative.value(x),
+    ///     (3.0 * x.powi(2)).cos() * (3.0 * (2.0 * x)) * x.exp() + (3.0 * x.powi(2)).sin() * x.exp(),
+    /// );
+    /// ```
+    ///
+    /// The above code does the same as the following mathematical expression:
+    /// ```math
+    /// f(x) = sin(3x^2) * e^x
+    /// f'(x) = 6x * cos(3x^2) * e^x + sin(3x^2) * e^x
+    /// ```
+    pub fn derivative(&self) -> Self {
+        if self.is_constant() {
+            return Self::Constant(0_f64);
+        }
+        match self {
+            Self::Constant(_) => Self::Constant(0_f64),
+            Self::X => Self::Constant(1_f64),
+            Self::Add(a, b) if a.is_constant() => b.derivative(),
+            Self::Add(a, b) | Self::Sub(a, b) if b.is_constant() => a.derivative(),
+            Self::Add(a, b) => a.derivative() + b.derivative(),
+            Self::Sub(a, b) if a.is_constant() => -b.derivative(),
+            Self::Sub(a, b) => a.derivative() - b.derivative(),
+            Self::Mul(a, b) if a.is_constant() => *a.clone() * b.derivative(),
+            Self::Mul(a, b) if b.is_constant() => a.derivative() * *b.clone(),
+            Self::Mul(a, b) => a.derivative() * *b.clone() + *a.clone() * b.derivative(),
+            Self::Div(a, b) if b.is_constant() => a.derivative() / *b.clone(),
+            Self::Div(a, b) => {
+                let numerator = if a.is_constant() {
+                    -(*a.clone() * b.derivative())
+                } else {
+                    a.derivative() * *b.clone() - *a.clone() * b.derivative()
+                };
+                numerator / b.clone().pow(Self::Constant(2_f64))
+            }
+            Self::Pow(_, exponent) if matches!(**exponent, Self::Constant(0_f64)) => {
+                // u^0 is constant, and n * u^(n - 1) would be undefined where u is 0
+                Self::Constant(0_f64)
+            }
+            Self::Pow(base, exponent) if exponent.is_constant() => {
+                // d/dx u^n = n * u^(n - 1) * u'
+                let reduced_exponent = match **exponent {
+                    Self::Constant(n) => Self::Constant(n - 1_f64),
+                    _ => *exponent.clone() - Self::Constant(1_f64),
+                };
+                Self::chain(*exponent.clone() * base.clone().pow(reduced_exponent), base)
+            }
+            Self::Pow(base, exponent) if base.is_constant() => {
+                // d/dx a^v = a^v * ln(a) * v'
+                Self::chain(self.clone() * base.clone().ln(), exponent)
+            }
+            Self::Pow(base, exponent) => {
+                // d/dx u^v = u^v * (v' * ln(u) + v * u' / u)
+                self.clone()
+                    * (exponent.derivative() * base.clone().ln()
+                        + *exponent.clone() * base.derivative() / *base.clone())
+            }
+            Self::Sin(a) => Self::chain(a.clone().cos(), a),
+            Self::Cos(a) => Self::chain(-a.clone().sin(), a),
+            Self::Tan(a) => a.derivative() / a.clone().cos().pow(Self::Constant(2_f64)),
+            Self::Exp(a) => Self::chain(self.clone(), a),
+            Self::Ln(a) => a.derivative() / *a.clone(),
+        }
+    }
+
+    /// Calculates the nth derivative of the expression, by taking the derivative `n` times.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::Expr;
+    ///
+    /// // d^2/dx^2 sin(x) = -sin(x)
+    /// let my_nth_derivative = Expr::X.sin().nth_derivative(2);
+    /// assert_eq!(my_nth_derivative.to_string(), "-1 * sin(x)");
+    /// assert_eq!(my_nth_derivative.value(1.0), -1.0_f64.sin());
+    ///
+    /// assert_eq!(Expr::X.exp().nth_derivative(0), Expr::X.exp());
+    /// ```
+    pub fn nth_derivative(&self, n: u32) -> Self {
+        (0..n).fold(self.clone(), |expression, _| expression.derivative())
+    }
+
+    /// Simplifies the expression into canonical form, so that expressions can be compared structurally.
+    ///
+    /// The expression is multiplied out into a sum of terms, each a coefficient times a product of powers, and then:
+    /// - subexpressions without x are folded into numbers, unless their value is not finite, as for `ln(0)`;
+    /// - coefficients of `1` and exponents of `0` and `1` are left out, as are terms with a coefficient of `0`;
+    /// - like terms are combined, with `Polynomial::simplified()` for the powers of x;
+    /// - powers of the same base are merged, as in `x^2 * x / x^4 = x^(-1)`, and so are exponentials, as in `exp(x) * exp(2 * x) = exp(3 * x)`,
+    ///   unless that would define the result where the expression is not, so `x / x` becomes `x * x^(-1)` rather than `1`, which is defined at 0;
+    /// - `a * sin(u)^2 + b * cos(u)^2` becomes `b + (a - b) * sin(u)^2`, so `sin(u)^2 + cos(u)^2` becomes `1`, and `ln(exp(u))` becomes `u`.
+    ///
+    /// In canonical form, the powers of x come first in descending order, then the other terms, then the constant.
+    /// Sums are only multiplied out when they are raised to an integer power from 1 to 16, and powers of powers are only merged when both exponents are integers,
+    /// as `(x^2)^0.5` and `(x^0.5)^2` are not `x` for negative x.
+    /// Like `Polynomial::simplified()`, only coefficients and exponents which are exactly zero are left out.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::Expr;
+    ///
+    /// // 0 * x + 1 * cos(x) * 1 = cos(x)
+    /// let my_expression = Expr::from(0.0) * Expr::X + Expr::from(1.0) * Expr::X.cos() * Expr::from(1.0);
+    /// assert_eq!(my_expression.simplify(), Expr::X.cos());
+    ///
+    /// // d/dx (sin(3x^2) * e^x) = 6x * cos(3x^2) * e^x + sin(3x^2) * e^x
+    /// let my_expression = (Expr::from(3.0) * Expr::X.pow(Expr::from(2.0))).sin() * Expr::X.exp();
+    /// assert_eq!(
+    ///     my_expression.derivative().simplify().to_string(),
+    ///     "6 * x * cos(3 * x^2) * exp(x) + sin(3 * x^2) * exp(x)",
+    /// );
+    ///
+    /// // (x + 1)(x - 1) + sin(x)^2 + cos(x)^2 = x^2
+    /// let my_expression = (Expr::X + Expr::from(1.0)) * (Expr::X - Expr::from(1.0))
+    ///     + Expr::X.sin().pow(Expr::from(2.0))
+    ///     + Expr::X.cos().pow(Expr::from(2.0));
+    /// assert_eq!(my_expression.simplify(), Expr::X.pow(Expr::from(2.0)));
+    /// ```
+    pub fn simplify(&self) -> Self {
+        simplify::simplify(self)
+    }
+
+    /// Applies the chain rule, multiplying the derivative of an outer function by the derivative of its argument, unless the argument is just x.
+    fn chain(outer_derivative: Self, inner: &Self) -> Self {
+        if *inner == Self::X {
+            outer_derivative
+        } else {
+            outer_derivative * inner.derivative()
+        }
+    }
+
+    /// How tightly the expression binds when it is written, so that brackets can be placed where they are needed.
+    fn precedence(&self) -> u8 {
+        match self {
+            Self::Add(..) | Self::Sub(..) => 1,
+            Self::Mul(..) | Self::Div(..) => 2,
+            Self::Pow(..) => 3,
+            Self::Constant(_)
+            | Self::X
+            | Self::Sin(_)
+            | Self::Cos(_)
+            | Self::Tan(_)
+            | Self::Exp(_)
+            | Self::Ln(_) => 4,
+        }
+    }
+
+    /// Checks if the expression is written starting with a minus sign, like `-2` or `-1 * x`.
+    fn is_written_negative(&self) -> bool {
+        match self {
+            Self::Constant(c) => c.is_sign_negative(),
+            Self::Mul(a, _) | Self::Div(a, _) => a.is_written_negative(),
+            _ => false,
+        }
+    }
+
+    /// Writes a binary operator and its operands, putting brackets around the operands where they are needed.
+    ///
+    /// An operand which binds less tightly than the operator needs them, and so does a right operand which binds as tightly, as in `x - (x - 1)`,
+    /// unless the operator groups to the right like `^`.
+    /// An operand which starts with a minus sign only goes without them at the start of a sum or product, as in `-1 * x + 2`.
+    fn fmt_binary(
+        f: &mut fmt::Formatter<'_>,
+        left: &Self,
+        operator: &str,
+        right: &Self,
+        precedence: u8,
+    ) -> fmt::Result {
+        let is_power = precedence == 3;
+        let left_minimum = if is_power { precedence + 1 } else { precedence };
+        let right_minimum = if is_power { precedence } else { precedence + 1 };
+        if left.precedence() < left_minimum || (is_power && left.is_written_negative()) {
+            write!(f, "({left})")?;
+        } else {
+            write!(f, "{left}")?;
+        }
+        write!(f, "{operator}")?;
+        if right.precedence() < right_minimum || right.is_written_negative() {
+            write!(f, "({right})")
+        } else {
+            write!(f, "{right}")
+        }
+    }
+}
+
+/// Formats the expression with the usual order of operations, e.g. `sin(x) * (3 * x^2 + 1)`.
+///
+/// Brackets are only written where they are needed, and around anything starting with a minus sign which does not begin a sum or product.
+///
+/// #### Example
+/// ```rust
+/// use calcucalc::Expr;
+///
+/// let my_expression = (Expr::X + Expr::from(1.0)) * Expr::X.tan();
+/// assert_eq!(my_expression.to_string(), "(x + 1) * tan(x)");
+///
+/// let my_expression = Expr::X - (Expr::X - Expr::from(-2.0));
+/// assert_eq!(my_expression.to_string(), "x - (x - (-2))");
+///
+/// let my_expression = Expr::X.pow(Expr::X.pow(Expr::from(2.0))) / Expr::from(-3.0).pow(Expr::X);
+/// assert_eq!(my_expression.to_string(), "x^x^2 / (-3)^x");
+/// ```
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Constant(c) => write!(f, "{c}"),
+            Self::X => write!(f, "x"),
+            Self::Add(a, b) => Self::fmt_binary(f, a, " + ", b, 1),
+            Self::Sub(a, b) => Self::fmt_binary(f, a, " - ", b, 1),
+            Self::Mul(a, b) => Self::fmt_binary(f, a, " * ", b, 2),
+            Self::Div(a, b) => Self::fmt_binary(f, a, " / ", b, 2),
+            Self::Pow(a, b) => Self::fmt_binary(f, a, "^", b, 3),
+            Self::Sin(a) => write!(f, "sin({a})"),
+            Self::Cos(a) => write!(f, "cos({a})"),
+            Self::Tan(a) => write!(f, "tan({a})"),
+            Self::Exp(a) => write!(f, "exp({a})"),
+            Self::Ln(a) => write!(f, "ln({a})"),
+        }
+    }
+}
+
+impl From<f64> for Expr {
+    fn from(value: f64) -> Self {
+        Self::Constant(value)
+    }
+}
+
+/// Converts the monomial `cx^e` into the expression `c * x^e`.
+impl From<&Monomial> for Expr {
+    fn from(monomial: &Monomial) -> Self {
+        Self::Constant(monomial.c) * Self::X.pow(Self::Constant(monomial.e))
+    }
+}
+
+/// Converts the monomial `cx^e` into the expression `c * x^e`.
+impl From<Monomial> for Expr {
+    fn from(monomial: Monomial) -> Self {
+        Self::from(&monomial)
+    }
+}
+
+/// Converts the polynomial into the sum of its monomials, in the order they are stored in, or into the constant `0` if it has none.
+///
+/// #### Example
+/// ```rust
+/// use calcucalc::{Expr, Monomial, Polynomial};
+///
+/// let my_polynomial = Polynomial(vec![
+///     Monomial { c: -1.0, e: 0.5 },
+///     Monomial { c: 2.0, e: -1.0 },
+/// ]);
+/// let my_expression = Expr::from(&my_polynomial);
+/// assert_eq!(my_expression.to_string(), "-1 * x^0.5 + 2 * x^(-1)");
+/// assert_eq!(my_expression.value(4.0), my_polynomial.value(4.0));
+/// assert_eq!(Expr::from(Polynomial::new()), Expr::Constant(0.0));
+/// ```
+impl From<&Polynomial> for Expr {
+    fn from(polynomial: &Polynomial) -> Self {
+        polynomial
+            .0
+            .iter()
+            .map(Self::from)
+            .reduce(Add::add)
+            .unwrap_or(Self::Constant(0_f64))
+    }
+}
+
+/// Converts the polynomial into the sum of its monomials, like `Expr::from(&Polynomial)`.
+impl From<Polynomial> for Expr {
+    fn from(polynomial: Polynomial) -> Self {
+        Self::from(&polynomial)
+    }
+}
+
+impl Add for Expr {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::Add(Box::new(self), Box::new(rhs))
+    }
+}
+
+impl Sub for Expr {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::Sub(Box::new(self), Box::new(rhs))
+    }
+}
+
+impl Mul for Expr {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Self::Mul(Box::new(self), Box::new(rhs))
+    }
+}
+
+impl Div for Expr {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Self::Div(Box::new(self), Box::new(rhs))
+    }
+}
+
+/// Negates the expression, as `-1 * self`.
+impl Neg for Expr {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::Constant(-1_f64) * self
+    }
+}
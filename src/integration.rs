@@ -0,0 +1,171 @@
+//! Types used when integrating monomials and polynomials.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{CalcError, DomainError, Polynomial, Scalar, math_helpers};
+
+/// The antiderivative of a polynomial.
+///
+/// The power rule turns every term `c * x^e` into `c / (e + 1) * x^(e + 1)`, except for `x^-1`, whose antiderivative is `ln|x|`. Such terms cannot be represented as a `Monomial`, so they are collected into `log_coefficient` instead.
+///
+/// #### Example
+/// ```rust
+/// use calcucalc::{Monomial, Polynomial};
+///
+/// // f(x) = 2x + 3/x
+/// let f = Polynomial(vec![
+///     Monomial { c: 2.0, e: 1.0 },
+///     Monomial { c: 3.0, e: -1.0 },
+/// ]);
+///
+/// // F(x) = x^2 + 3ln|x| + 5
+/// let big_f = f.antiderivative(5.0).unwrap();
+/// assert_eq!(big_f.polynomial, Polynomial(vec![
+///     Monomial { c: 1.0, e: 2.0 },
+///     Monomial { c: 5.0, e: 0.0 },
+/// ]));
+/// assert_eq!(big_f.log_coefficient, 3.0);
+/// assert_eq!(big_f.value(1.0), 6.0);
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[must_use]
+pub struct Antiderivative<T = f64> {
+    /// The power-rule part of the antiderivative, including the constant of integration.
+    pub polynomial: Polynomial<T>,
+    /// The coefficient of the `ln|x|` term.
+    pub log_coefficient: T,
+}
+
+impl Antiderivative {
+    /// Calculates the value of the antiderivative for a given value of x.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{Monomial, Polynomial};
+    ///
+    /// let f = Polynomial(vec![Monomial { c: 1.0, e: -1.0 }]);
+    /// let big_f = f.antiderivative(0.0).unwrap();
+    /// assert_eq!(big_f.value(std::f64::consts::E), 1.0);
+    /// assert_eq!(big_f.value(-std::f64::consts::E), 1.0);
+    /// ```
+    #[must_use]
+    pub fn value(&self, x: f64) -> f64 {
+        let mut value = self.polynomial.value(x);
+        if self.log_coefficient != 0_f64 {
+            value += self.log_coefficient * x.abs().ln();
+        }
+        value
+    }
+}
+
+impl<T: Scalar> Antiderivative<T> {
+    /// Whether the antiderivative contains a `ln|x|` term.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{Monomial, Polynomial};
+    ///
+    /// let f = Polynomial(vec![Monomial { c: 1.0, e: 2.0 }]);
+    /// assert!(!f.antiderivative(0.0).unwrap().has_log_term());
+    /// ```
+    #[must_use]
+    pub fn has_log_term(&self) -> bool {
+        self.log_coefficient != T::zero()
+    }
+}
+
+impl Polynomial {
+    /// Calculates the definite integral of the polynomial from `a` to `b`.
+    ///
+    /// The integral is evaluated with the antiderivative of the polynomial, so it is exact up to floating point error.
+    /// If `a` is greater than `b`, the sign of the result is flipped, as usual.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{Monomial, Polynomial};
+    ///
+    /// let my_polynomial = Polynomial(vec![
+    ///     Monomial { c: 3.0, e: 2.0 },
+    ///     Monomial { c: 1.0, e: 0.0 },
+    /// ]);
+    /// assert_eq!(my_polynomial.definite_integral(0.0, 2.0).unwrap(), 10.0);
+    /// assert_eq!(my_polynomial.definite_integral(2.0, 0.0).unwrap(), -10.0);
+    /// ```
+    ///
+    /// Terms with negative exponents are singular at `x = 0`. If `0` lies within the interval, the integral is improper. When the improper integral converges, its limit is returned:
+    /// ```rust
+    /// use calcucalc::{Monomial, Polynomial};
+    ///
+    /// // The integral of 1/√x from 0 to 4 is 4.
+    /// let my_polynomial = Polynomial(vec![Monomial { c: 1.0, e: -0.5 }]);
+    /// assert_eq!(my_polynomial.definite_integral(0.0, 4.0).unwrap(), 4.0);
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// - `DomainError::DivergentIntegral` if a term such as `x^-2` has a singularity at `0` within the interval and its improper integral diverges.
+    /// - `DomainError::FractionalPowerOfNegative` if a term with a fractional exponent, such as `x^0.5`, would be evaluated for negative x.
+    /// - `CalcError::NonFinite` if the result overflows or is otherwise not finite.
+    ///
+    /// ```rust
+    /// use calcucalc::{CalcError, DomainError, Monomial, Polynomial};
+    ///
+    /// let p1 = Polynomial(vec![Monomial { c: 1.0, e: -2.0 }]);
+    /// assert_eq!(
+    ///     p1.definite_integral(-1.0, 1.0),
+    ///     Err(CalcError::Domain(DomainError::DivergentIntegral { exponent: -2.0, singularity: 0.0 }))
+    /// );
+    ///
+    /// let p2 = Polynomial(vec![Monomial { c: 1.0, e: 0.5 }]);
+    /// assert_eq!(
+    ///     p2.definite_integral(-1.0, 1.0),
+    ///     Err(CalcError::Domain(DomainError::FractionalPowerOfNegative { exponent: 0.5 }))
+    /// );
+    /// ```
+    pub fn definite_integral(&self, a: f64, b: f64) -> Result<f64, CalcError> {
+        // Validate the start and end x-values are in the correct order,
+        // and swap them if they are not, remembering to flip the sign.
+        let (start_x, end_x, sign) = if a > b { (b, a, -1_f64) } else { (a, b, 1_f64) };
+
+        let integrand = self.simplified()?;
+
+        // An empty interval contributes nothing, even at a singularity.
+        if start_x >= end_x {
+            return Ok(0_f64);
+        }
+
+        for element in &integrand.0 {
+            let is_integer_power =
+                math_helpers::is_equal_within_tolerance_to(&element.e, &element.e.round());
+
+            // Fractional powers of negative numbers are not real.
+            if !is_integer_power && start_x < 0_f64 {
+                return Err(DomainError::FractionalPowerOfNegative {
+                    exponent: element.e,
+                }
+                .into());
+            }
+
+            // Negative powers are singular at 0. The improper integral only
+            // converges for exponents strictly between -1 and 0, in which case
+            // the antiderivative is 0 at x = 0 and no special handling is needed.
+            let contains_zero = start_x <= 0_f64 && 0_f64 <= end_x;
+            let diverges = element.e <= -1_f64
+                || math_helpers::is_equal_within_tolerance_to(&element.e, &-1_f64);
+            if contains_zero && diverges {
+                return Err(DomainError::DivergentIntegral {
+                    exponent: element.e,
+                    singularity: 0_f64,
+                }
+                .into());
+            }
+        }
+
+        let antiderivative = integrand.antiderivative(0_f64)?;
+        let integral = sign * (antiderivative.value(end_x) - antiderivative.value(start_x));
+        if !integral.is_finite() {
+            return Err(CalcError::NonFinite(integral));
+        }
+        Ok(integral)
+    }
+}
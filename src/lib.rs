@@ -29,11 +29,44 @@
 //! This documentation provides examples of how to use the library. Just navigate to the item you want to learn more about, and the description and examples will be there.
 //!
 //! Overall, this library relies heavily on the use of the `f64` type for the sake of flexibility and generality.
+//! Monomials and polynomials can use other number types too, through the `Scalar` trait.
 //!
 
 use serde::{Deserialize, Serialize};
 
+pub mod analysis;
+mod coefficients;
+pub mod complex;
+pub mod dense;
+mod display;
+pub mod error;
+pub mod expr;
+pub mod integration;
 pub mod math_helpers;
+mod multiplication;
+mod multipoint;
+mod ops;
+pub mod parse;
+pub mod rational;
+pub mod roots;
+pub mod scalar;
+mod simplify;
+pub mod tolerance;
+
+pub use analysis::{
+    Concavity, ConcavityAnalysis, ConcavityInterval, CriticalPoint, CriticalPointKind, Extrema,
+    Extremum, InflectionPoint, MonotonicInterval, Trend,
+};
+pub use complex::Complex;
+pub use dense::DensePolynomial;
+pub use error::{CalcError, DomainError};
+pub use expr::Expr;
+pub use integration::Antiderivative;
+pub use parse::{ParseError, ParseErrorKind};
+pub use rational::Rational;
+pub use roots::{ComplexRoot, RealRoot};
+pub use scalar::Scalar;
+pub use tolerance::Tolerance;
 
 /// A monomial is a product of a coefficient and an exponent of x.
 /// For example, in the monomial `3x^2`, the coefficient is `3` and the exponent of x is `2`.
@@ -61,6 +94,8 @@ pub mod math_helpers;
 /// ```
 /// This library is intended to be as general-purpose as possible, which is why the coefficient and exponent are represented as floating-point numbers (as opposed to integers). This allows for more flexibility in the types of functions that can be represented.
 ///
+/// The coefficient and exponent are `f64` by default, but any type implementing `Scalar` can be used instead, e.g. `Monomial<f32>`.
+///
 /// Here is a table showing example monomials and their corresponding struct representations:
 /// | Monomial | Struct Representation |
 /// | --- | --- |
@@ -89,14 +124,14 @@ pub mod math_helpers;
 ///
 #[derive(Clone, Debug, Default, Deserialize, PartialEq, PartialOrd, Serialize)]
 #[must_use]
-pub struct Monomial {
+pub struct Monomial<T = f64> {
     /// <u>c</u>oefficient
-    pub c: f64, // Coefficient
+    pub c: T, // Coefficient
     /// <u>e</u>xponent
-    pub e: f64, // Exponent
+    pub e: T, // Exponent
 }
 
-impl Monomial {
+impl<T: Scalar> Monomial<T> {
     /// Creates a new monomial
     ///
     /// #### Example
@@ -106,7 +141,7 @@ impl Monomial {
     /// let m = Monomial::new(1.0, 2.0);
     /// assert_eq!(m, Monomial { c: 1.0, e: 2.0 });
     /// ```
-    pub fn new(c: f64, e: f64) -> Self {
+    pub fn new(c: T, e: T) -> Self {
         Self { c, e }
     }
 
@@ -122,9 +157,13 @@ impl Monomial {
     /// assert_eq!(m.value(4.0), 128.0);
     /// assert_eq!(m.value(5.0), 250.0);
     /// ```
+    ///
+    /// ## Panics
+    ///
+    /// With `Rational` numbers, panics if a power of x is not a fraction which fits in `i64`, as for `2^(1/2)`, or if the value overflows `i64`. Use `checked_value()` to get an error instead.
     #[must_use]
-    pub fn value(&self, x: f64) -> f64 {
-        self.c * (x.powf(self.e))
+    pub fn value(&self, x: T) -> T {
+        self.c.clone() * (x.pow(&self.e))
     }
 
     /// Adds one monomial to another, if they have the same exponent of x.
@@ -141,15 +180,21 @@ impl Monomial {
     /// 
     /// ## Errors
     /// 
-    /// If the two monomials do not have the same exponent of x, an error is returned.
+    /// If the two monomials do not have the same exponent of x, a `CalcError::MismatchedExponents` is returned.
     ///
-    pub fn add_monomial_of_same_power(&self, other: &Self) -> Result<Self, String> {
-        if !math_helpers::is_equal_within_tolerance_to(&self.e, &other.e) {
-            return Err("Cannot add monomials with different powers of x.".to_string());
+    /// ## Panics
+    ///
+    /// With `Rational` numbers, panics if the sum of the coefficients overflows `i64`.
+    pub fn add_monomial_of_same_power(&self, other: &Self) -> Result<Self, CalcError> {
+        if !self.e.is_equal_within_tolerance_to(&other.e) {
+            return Err(CalcError::MismatchedExponents {
+                left: self.e.to_f64(),
+                right: other.e.to_f64(),
+            });
         }
         Ok(Self {
-            c: self.c + other.c,
-            e: self.e,
+            c: self.c.clone() + other.c.clone(),
+            e: self.e.clone(),
         })
     }
 
@@ -164,10 +209,14 @@ impl Monomial {
     /// let m3 = Monomial { c: 2.0, e: 6.283185307179586 };
     /// assert_eq!(m3, m1.multiply_monomial(&m2));
     /// ```
+    ///
+    /// ## Panics
+    ///
+    /// With `Rational` numbers, panics if the product of the coefficients or the sum of the exponents overflows `i64`.
     pub fn multiply_monomial(&self, other: &Self) -> Self {
         Self {
-            c: self.c * other.c,
-            e: self.e + other.e,
+            c: self.c.clone() * other.c.clone(),
+            e: self.e.clone() + other.e.clone(),
         }
     }
 
@@ -182,10 +231,14 @@ impl Monomial {
     /// let m_derivative = Monomial { c: 3.141592653589793, e: 2.141592653589793 };
     /// assert_eq!(m_derivative, m.derivative());
     /// ```
+    ///
+    /// ## Panics
+    ///
+    /// With `Rational` numbers, panics if the coefficient times the exponent overflows `i64`.
     pub fn derivative(&self) -> Self {
         Self {
-            c: self.c * self.e,
-            e: self.e - 1_f64,
+            c: self.c.clone() * self.e.clone(),
+            e: self.e.clone() - T::one(),
         }
     }
 
@@ -201,6 +254,10 @@ impl Monomial {
     /// let m_nth_derivative = m.nth_derivative(2);
     /// assert_eq!(m_nth_derivative, Monomial { c: 6.728011747499565, e: 1.1415926535897931 });
     /// ```
+    ///
+    /// ## Panics
+    ///
+    /// With `Rational` numbers, panics if a coefficient of one of the derivatives overflows `i64`.
     pub fn nth_derivative(&self, n: u32) -> Self {
         let mut new_monomial = self.clone();
         for _ in 0..n {
@@ -209,8 +266,52 @@ impl Monomial {
         new_monomial
     }
 
+    /// Calculates the antiderivative of the monomial, without a constant of integration.
+    /// The antiderivative of a monomial is the coefficient divided by the exponent plus one, times x raised to the power of the exponent plus one.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::Monomial;
+    ///
+    /// let m = Monomial { c: 6.0, e: 2.0 };
+    /// assert_eq!(m.antiderivative().unwrap(), Monomial { c: 2.0, e: 3.0 });
+    /// assert_eq!(m.antiderivative().unwrap().derivative(), m);
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// If the exponent of x is `-1`, a `DomainError::LogarithmicTerm` is returned, because the antiderivative of `x^-1` is `ln|x|`, which cannot be represented as a monomial.
+    /// Use `Polynomial::antiderivative()` to integrate such terms.
+    ///
+    /// ```rust
+    /// use calcucalc::{CalcError, DomainError, Monomial};
+    ///
+    /// let m = Monomial { c: 5.0, e: -1.0 };
+    /// assert_eq!(
+    ///     m.antiderivative(),
+    ///     Err(CalcError::Domain(DomainError::LogarithmicTerm { coefficient: 5.0 }))
+    /// );
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// With `Rational` numbers, panics if the new exponent or coefficient overflows `i64`.
+    pub fn antiderivative(&self) -> Result<Self, CalcError> {
+        if self.e.is_equal_within_tolerance_to(&-T::one()) {
+            return Err(DomainError::LogarithmicTerm {
+                coefficient: self.c.to_f64(),
+            }
+            .into());
+        }
+        let e = self.e.clone() + T::one();
+        Ok(Self {
+            c: self.c.clone() / e.clone(),
+            e,
+        })
+    }
+
     /// Checks if two monomials are equal within a certain tolerance.
-    /// The default tolerance is determined by the `is_equal_within_tolerance_to` function in the `math_helpers` module.
+    /// The tolerance is determined by the `Scalar` type; for `f64` it is the crate-wide default `Tolerance`.
     /// 
     /// #### Example
     /// ```rust
@@ -222,8 +323,24 @@ impl Monomial {
     /// ```
     #[must_use]
     pub fn is_equal_within_tolerance_to(&self, other: &Self) -> bool {
-        let c_equal = math_helpers::is_equal_within_tolerance_to(&self.c, &other.c);
-        let e_equal = math_helpers::is_equal_within_tolerance_to(&self.e, &other.e);
+        self.is_equal_within(other, T::default_tolerance())
+    }
+
+    /// Checks if two monomials are equal within the given tolerance.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{Monomial, Tolerance};
+    ///
+    /// let m1 = Monomial { c: 241346513.3, e: 2.0 };
+    /// let m2 = Monomial { c: 241346513.30001, e: 2.0 };
+    /// assert!(!m1.is_equal_within(&m2, Tolerance::Absolute(1e-10)));
+    /// assert!(m1.is_equal_within(&m2, Tolerance::Relative(1e-12)));
+    /// ```
+    #[must_use]
+    pub fn is_equal_within(&self, other: &Self, tolerance: Tolerance) -> bool {
+        let c_equal = self.c.is_equal_within(&other.c, tolerance);
+        let e_equal = self.e.is_equal_within(&other.e, tolerance);
 
         c_equal && e_equal
     }
@@ -242,9 +359,16 @@ impl Monomial {
 ///    Monomial { c: 1.0, e: 0.0 },
 /// ]);
 /// ```
+///
+/// Like `Monomial`, a polynomial uses `f64` by default, but can use any type implementing `Scalar`.
+/// Simplifying, adding, multiplying, differentiating and integrating work for any `Scalar`, while methods such as root finding and interval analysis need `f64`.
+///
+/// A polynomial built by hand can hold its monomials in any order, but every operation which simplifies its result returns it in canonical form:
+/// sorted by exponent of x in descending order, with no two exponents alike and no coefficients of `0` (see `is_canonical()`).
+/// Sums of canonical polynomials are then found by merging their monomials in linear time, rather than simplifying from scratch.
 #[derive(Clone, Debug, Default, Deserialize, PartialEq, PartialOrd, Serialize)]
 #[must_use]
-pub struct Polynomial(pub Vec<Monomial>);
+pub struct Polynomial<T = f64>(pub Vec<Monomial<T>>);
 
 impl Polynomial {
     /// Creates a new polynomial with no monomials.
@@ -261,6 +385,69 @@ impl Polynomial {
         Self(vec![])
     }
 
+    /// Calculates the values of the polynomial for many values of x at once.
+    ///
+    /// See `evaluate_into()` for how this is faster than calling `value()` for each of them.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{Monomial, Polynomial};
+    ///
+    /// let my_polynomial = Polynomial(vec![
+    ///    Monomial { c: 1.0, e: 2.0 },
+    ///    Monomial { c: 3.0, e: 1.0 },
+    ///    Monomial { c: 2.0, e: 0.0 },
+    /// ]);
+    /// assert_eq!(my_polynomial.values(&[-1.0, 0.0, 1.0, 2.0, 3.0]), vec![0.0, 2.0, 6.0, 12.0, 20.0]);
+    /// ```
+    #[must_use]
+    pub fn values(&self, xs: &[f64]) -> Vec<f64> {
+        let mut values = vec![0_f64; xs.len()];
+        self.evaluate_into(xs, &mut values);
+        values
+    }
+
+    /// Calculates the values of the polynomial for many values of x at once, writing them into `values`.
+    ///
+    /// If the exponents of the polynomial are mostly consecutive non-negative integers, it is converted into a `DensePolynomial` once,
+    /// and every value is calculated with `DensePolynomial::evaluate_into()` instead of raising x to the power of each exponent.
+    /// The values can then differ from those of `value()` by rounding.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{Monomial, Polynomial};
+    ///
+    /// let my_polynomial = Polynomial(vec![
+    ///    Monomial { c: 2.0, e: 0.5 },
+    ///    Monomial { c: -1.0, e: 0.0 },
+    /// ]);
+    /// let mut values = [0.0; 3];
+    /// my_polynomial.evaluate_into(&[1.0, 4.0, 9.0], &mut values);
+    /// assert_eq!(values, [1.0, 3.0, 5.0]);
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if `xs` and `values` have different lengths.
+    pub fn evaluate_into(&self, xs: &[f64], values: &mut [f64]) {
+        assert_eq!(
+            xs.len(),
+            values.len(),
+            "xs and values must have the same length"
+        );
+        if coefficients::is_dense(self)
+            && let Ok(dense_polynomial) = DensePolynomial::try_from(self)
+        {
+            dense_polynomial.evaluate_into(xs, values);
+            return;
+        }
+        for (x, value) in xs.iter().zip(values.iter_mut()) {
+            *value = self.value(*x);
+        }
+    }
+}
+
+impl<T: Scalar> Polynomial<T> {
     /// Calculates the value of a polynomial for a given value of x.
     ///
     /// #### Example
@@ -278,12 +465,19 @@ impl Polynomial {
     /// assert_eq!(my_polynomial.value(2.0), 12.0);
     /// assert_eq!(my_polynomial.value(3.0), 20.0);
     /// ```
+    ///
+    /// `x` is taken by value, like in `Monomial::value()`, so that plain numbers can be passed.
+    ///
+    /// ## Panics
+    ///
+    /// With `Rational` numbers, panics if a power of x is not a fraction which fits in `i64`, as for `2^(1/2)`, or if the sum overflows `i64`. Use `checked_value()` to get an error instead.
     #[must_use]
-    pub fn value(&self, x: f64) -> f64 {
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn value(&self, x: T) -> T {
         let elements = &self.0;
-        let mut value = 0_f64;
+        let mut value = T::zero();
         for element in elements {
-            value += element.value(x);
+            value = value + element.value(x.clone());
         }
         value
     }
@@ -320,15 +514,149 @@ impl Polynomial {
     /// 
     /// ## Errors
     /// 
-    /// If two monomials with different exponents are attempted to be combined, an error is returned.
-    pub fn simplified(&self) -> Result<Self, String> {
-        Ok(self.simplify_by_combining_alike_powers()?
-            .eliminate_zero_coefficients()
-            .sort_by_exponent())
+    /// Never returns an error; the `Result` is kept for compatibility with earlier versions, in which combining alike powers could fail.
+    ///
+    /// ## Panics
+    ///
+    /// With `Rational` numbers, panics if the sum of the coefficients of alike powers overflows `i64`.
+    pub fn simplified(&self) -> Result<Self, CalcError> {
+        Ok(self
+            .sorted_sums(T::default_tolerance())
+            .eliminate_zero_coefficients())
+    }
+
+    /// Simplifies the polynomial like `simplified()`, but combines exponents which are equal within the given tolerance, and eliminates coefficients which are equal to `0` within it.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{Monomial, Polynomial, Tolerance};
+    ///
+    /// let my_polynomial = Polynomial(vec![
+    ///     Monomial { c: 1e-14, e: 2.0 },
+    ///     Monomial { c: 2e-14, e: 1.0 },
+    ///     Monomial { c: 3e-14, e: 1.0 + 1e-12 },
+    /// ]);
+    ///
+    /// let expected = Polynomial(vec![
+    ///     Monomial { c: 1e-14, e: 2.0 },
+    ///     Monomial { c: 5e-14, e: 1.0 },
+    /// ]);
+    /// assert_eq!(my_polynomial.simplified().unwrap(), expected);
+    /// assert_eq!(my_polynomial.simplified_with_tolerance(Tolerance::Relative(1e-9)), expected);
+    ///
+    /// // Every coefficient is within an absolute tolerance of 1e-10 of zero.
+    /// assert_eq!(my_polynomial.simplified_with_tolerance(Tolerance::Absolute(1e-10)), Polynomial::new());
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// With `Rational` numbers, panics if the sum of the coefficients of alike powers overflows `i64`.
+    pub fn simplified_with_tolerance(&self, tolerance: Tolerance) -> Self {
+        self.sorted_sums(tolerance)
+            .eliminate_zero_coefficients_with_tolerance(tolerance)
+    }
+
+    /// Combines the elements whose exponents are alike, sorted by exponent of x in descending order, without eliminating any of them.
+    fn sorted_sums(&self, tolerance: Tolerance) -> Self {
+        Self(
+            self.combined_groups(tolerance)
+                .into_iter()
+                .map(|(_, element)| element)
+                .collect(),
+        )
+    }
+
+    /// Checks if the polynomial is in canonical form, as returned by `simplified()`:
+    /// sorted by exponent of x in descending order, with no two exponents alike and no coefficients of `0`.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{Monomial, Polynomial};
+    ///
+    /// let my_polynomial = Polynomial(vec![
+    ///     Monomial { c: 1.0, e: 1.0 },
+    ///     Monomial { c: 3.0, e: 2.0 },
+    /// ]);
+    /// assert!(!my_polynomial.is_canonical());
+    /// assert!(my_polynomial.simplified().unwrap().is_canonical());
+    /// ```
+    #[must_use]
+    pub fn is_canonical(&self) -> bool {
+        self.is_canonical_within(T::default_tolerance())
+    }
+
+    /// Checks if the polynomial is in canonical form, with no two exponents alike under the given tolerance.
+    fn is_canonical_within(&self, tolerance: Tolerance) -> bool {
+        let zero = T::zero();
+        self.0.iter().all(|element| element.c != zero)
+            && self.0.windows(2).all(|pair| {
+                pair[0].e > pair[1].e && !pair[0].e.is_equal_within(&pair[1].e, tolerance)
+            })
+    }
+
+    /// Groups the elements whose exponents are alike, returning each group's sum in descending order of exponent, together with the index of the group's first element.
+    ///
+    /// The elements are sorted by exponent first, so that only neighbouring elements need to be compared, which takes O(n log n) time rather than O(n²).
+    /// Each sum keeps the exponent of the first of its elements, and adds up their coefficients in the order they are stored in.
+    fn combined_groups(&self, tolerance: Tolerance) -> Vec<(usize, Monomial<T>)> {
+        let mut order: Vec<usize> = (0..self.0.len()).collect();
+        order.sort_by(|&a, &b| descending_exponents(&self.0[a].e, &self.0[b].e));
+
+        // (index of the first element, sum of the elements, largest exponent)
+        let mut groups: Vec<(usize, Monomial<T>, T)> = vec![];
+        for index in order {
+            let element = &self.0[index];
+            match groups.last_mut() {
+                Some((first, sum, largest)) if element.e.is_equal_within(largest, tolerance) => {
+                    sum.c = sum.c.clone() + element.c.clone();
+                    if index < *first {
+                        *first = index;
+                        sum.e = element.e.clone();
+                    }
+                }
+                _ => groups.push((index, element.clone(), element.e.clone())),
+            }
+        }
+        groups
+            .into_iter()
+            .map(|(first, sum, _)| (first, sum))
+            .collect()
+    }
+
+    /// Adds two canonical polynomials by merging their elements, which takes linear time.
+    fn merged_with(&self, other: &Self, tolerance: Tolerance) -> Self {
+        let zero = T::zero();
+        let mut elements = Vec::with_capacity(self.0.len() + other.0.len());
+        let (mut left, mut right) = (0, 0);
+        while left < self.0.len() && right < other.0.len() {
+            let (left_element, right_element) = (&self.0[left], &other.0[right]);
+            if left_element.e.is_equal_within(&right_element.e, tolerance) {
+                let c = left_element.c.clone() + right_element.c.clone();
+                if c != zero {
+                    elements.push(Monomial {
+                        c,
+                        e: left_element.e.clone(),
+                    });
+                }
+                left += 1;
+                right += 1;
+            } else if left_element.e > right_element.e {
+                elements.push(left_element.clone());
+                left += 1;
+            } else {
+                elements.push(right_element.clone());
+                right += 1;
+            }
+        }
+        elements.extend_from_slice(&self.0[left..]);
+        elements.extend_from_slice(&other.0[right..]);
+        Self(elements)
     }
 
     /// Combines elements which have the same exponent of x.
     ///
+    /// The combined elements are kept in the order in which their exponents first appear.
+    ///
     /// #### Example
     /// ```rust
     /// use calcucalc::{Monomial, Polynomial};
@@ -345,37 +673,43 @@ impl Polynomial {
     /// ```
     /// ## Errors
     /// 
-    /// If two monomials with different exponents are attempted to be combined, an error is returned.
-    pub fn simplify_by_combining_alike_powers(&self) -> Result<Self, String> {
-        let elements = &self.0;
-        let mut simplified_elements = Self::new();
-
-        let mut first_element_transferred = false;
-
-        for element in elements {
-            if !first_element_transferred {
-                simplified_elements.0.push(element.clone());
-                first_element_transferred = true;
-                continue;
-            }
+    /// Never returns an error; the `Result` is kept for compatibility with earlier versions, in which combining alike powers could fail.
+    ///
+    /// ## Panics
+    ///
+    /// With `Rational` numbers, panics if the sum of the coefficients of alike powers overflows `i64`.
+    pub fn simplify_by_combining_alike_powers(&self) -> Result<Self, CalcError> {
+        Ok(self.simplify_by_combining_alike_powers_with_tolerance(T::default_tolerance()))
+    }
 
-            let mut found_match = false;
-            for simplified_element in &mut simplified_elements.0 {
-                if math_helpers::is_equal_within_tolerance_to(&element.e, &simplified_element.e) {
-                    *simplified_element =
-                        simplified_element.add_monomial_of_same_power(element)?;
-                    found_match = true;
-                    break;
-                }
-            }
-            if !found_match {
-                simplified_elements.0.push(element.clone());
-            }
-        }
-        Ok(simplified_elements)
+    /// Combines elements whose exponents of x are equal within the given tolerance.
+    ///
+    /// The combined element keeps the exponent of the first of them.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{Monomial, Polynomial, Tolerance};
+    ///
+    /// let my_polynomial = Polynomial(vec![
+    ///     Monomial { c: 1.0, e: 2.0 },
+    ///     Monomial { c: 2.0, e: 2.001 },
+    /// ]);
+    /// let simplified_polynomial = my_polynomial.simplify_by_combining_alike_powers_with_tolerance(Tolerance::Absolute(0.01));
+    /// assert_eq!(simplified_polynomial, Polynomial(vec![
+    ///     Monomial { c: 3.0, e: 2.0 },
+    /// ]));
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// With `Rational` numbers, panics if the sum of the coefficients of alike powers overflows `i64`.
+    pub fn simplify_by_combining_alike_powers_with_tolerance(&self, tolerance: Tolerance) -> Self {
+        let mut groups = self.combined_groups(tolerance);
+        groups.sort_by_key(|(first, _)| *first);
+        Self(groups.into_iter().map(|(_, element)| element).collect())
     }
 
-    /// Eliminates elements with coefficients of `0`.
+    /// Eliminates elements with coefficients of exactly `0`.
     ///
     /// As `0` multiplied by any number is `0`, elements with a coefficient of `0` do not affect the value of the polynomial, and can be safely ignored.
     ///
@@ -396,7 +730,39 @@ impl Polynomial {
         let elements = &self.0;
         let mut new_elements = vec![];
         for element in elements {
-            if element.c != 0_f64 {
+            if element.c != T::zero() {
+                new_elements.push(element.clone());
+            }
+        }
+        Self(new_elements)
+    }
+
+    /// Eliminates elements whose coefficients are equal to `0` within the given tolerance, rather than only those which are exactly `0`.
+    ///
+    /// Under a relative tolerance, only coefficients of exactly `0` are eliminated.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{Monomial, Polynomial, Tolerance};
+    ///
+    /// let my_polynomial = Polynomial(vec![
+    ///     Monomial { c: 1.0, e: 2.0 },
+    ///     Monomial { c: 1e-12, e: 1.0 },
+    ///     Monomial { c: 0.0, e: 0.0 },
+    /// ]);
+    /// assert_eq!(my_polynomial.eliminate_zero_coefficients_with_tolerance(Tolerance::Absolute(1e-10)), Polynomial(vec![
+    ///    Monomial { c: 1.0, e: 2.0 },
+    /// ]));
+    /// assert_eq!(my_polynomial.eliminate_zero_coefficients(), Polynomial(vec![
+    ///    Monomial { c: 1.0, e: 2.0 },
+    ///    Monomial { c: 1e-12, e: 1.0 },
+    /// ]));
+    /// ```
+    pub fn eliminate_zero_coefficients_with_tolerance(&self, tolerance: Tolerance) -> Self {
+        let elements = &self.0;
+        let mut new_elements = vec![];
+        for element in elements {
+            if !element.c.is_equal_within(&T::zero(), tolerance) {
                 new_elements.push(element.clone());
             }
         }
@@ -429,7 +795,7 @@ impl Polynomial {
     /// ```
     pub fn sort_by_exponent(&self) -> Self {
         let mut elements = self.0.clone();
-        elements.sort_by(|a, b| b.e.partial_cmp(&a.e).unwrap_or(std::cmp::Ordering::Equal));
+        elements.sort_by(|a, b| descending_exponents(&a.e, &b.e));
         Self(elements)
     }
 
@@ -454,16 +820,25 @@ impl Polynomial {
     /// ]));
     /// ```
     ///
-    /// `add_polynomial()` itself calls `simplified()` before returning the result.
+    /// `add_polynomial()` returns the result simplified, as by `simplified()`.
+    /// If both polynomials are already in canonical form, their monomials are merged in linear time.
     /// 
     /// ## Errors
     /// 
-    /// If two monomials with different exponents are attempted to be combined, an error is returned.
-    pub fn add_polynomial(&self, other: Self) -> Result<Self, String> {
+    /// Never returns an error, as simplification cannot fail; the `Result` is kept for compatibility.
+    ///
+    /// ## Panics
+    ///
+    /// With `Rational` numbers, panics if the sum of the coefficients of alike powers overflows `i64`.
+    pub fn add_polynomial(&self, other: Self) -> Result<Self, CalcError> {
+        let tolerance = T::default_tolerance();
+        if self.is_canonical_within(tolerance) && other.is_canonical_within(tolerance) {
+            return Ok(self.merged_with(&other, tolerance));
+        }
         let mut elements = self.0.clone();
-        elements.extend(other.0.clone());
+        elements.extend(other.0);
         let new_polynomial = Self(elements);
-        Ok(new_polynomial.simplified()?)
+        new_polynomial.simplified()
     }
 
     /// Multiplies one polynomial by another.
@@ -488,22 +863,368 @@ impl Polynomial {
     /// ]));
     /// ```
     ///
-    /// `multiply_polynomial()` itself calls `simplified()` before returning the result.
+    /// `multiply_polynomial()` returns the result simplified, as by `simplified()`.
+    /// Both polynomials are simplified first, so that multiplying one by each monomial of the other gives rows of products which are already sorted by exponent.
+    /// The rows are then merged in pairs, so multiplying polynomials of n and m monomials takes O(nm log n) time, without sorting all nm products.
+    /// For large `f64` polynomials whose exponents are mostly consecutive integers, Karatsuba or FFT-based multiplication is used instead (see `Scalar::fast_product()`).
     /// 
     /// ## Errors
     /// 
-    /// If two monomials with different exponents are attempted to be combined, an error is returned.
-    pub fn multiply_polynomial(&self, other: Self) -> Result<Self, String> {
-        let mut elements = vec![];
-        for element1 in &self.0 {
-            for element2 in &other.0 {
-                elements.push(element1.multiply_monomial(&element2));
+    /// Never returns an error, as simplification cannot fail; the `Result` is kept for compatibility.
+    ///
+    /// ## Panics
+    ///
+    /// With `Rational` numbers, panics if a product of coefficients, a sum of exponents, or a sum of the products overflows `i64`.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn multiply_polynomial(&self, other: Self) -> Result<Self, CalcError> {
+        if let Some(product) = T::fast_product(self, &other) {
+            return Ok(product);
+        }
+        let tolerance = T::default_tolerance();
+        let zero = T::zero();
+        let (left, right) = (self.simplified()?, other.simplified()?);
+        let mut rows: Vec<Self> = left
+            .0
+            .iter()
+            .map(|element1| {
+                Self(
+                    right
+                        .0
+                        .iter()
+                        .map(|element2| element1.multiply_monomial(element2))
+                        .filter(|element| element.c != zero)
+                        .collect(),
+                )
+            })
+            .collect();
+        while rows.len() > 1 {
+            let mut merged = Vec::with_capacity(rows.len().div_ceil(2));
+            let mut pairs = rows.into_iter();
+            while let Some(row) = pairs.next() {
+                merged.push(match pairs.next() {
+                    Some(next) => row.merged_with(&next, tolerance),
+                    None => row,
+                });
             }
+            rows = merged;
         }
-        let new_polynomial = Self(elements);
-        Ok(new_polynomial.simplified()?)
+
+        let product = rows.pop().unwrap_or(Self(vec![]));
+        // Rounding can make the sums of distinct exponents alike.
+        if product.is_canonical_within(tolerance) {
+            Ok(product)
+        } else {
+            product.simplified()
+        }
+    }
+}
+
+impl Polynomial {
+    /// Divides one polynomial by another using polynomial long division, returning the quotient and the remainder.
+    ///
+    /// Both polynomials must have non-negative integer exponents.
+    /// The quotient and remainder satisfy `self = quotient * divisor + remainder`, where the remainder has a lower degree than the divisor.
+    /// Remainder coefficients which are zero within the tolerance of the `math_helpers` module (relative to the size of `self`) are dropped, so an exact-enough division leaves an empty remainder.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{Monomial, Polynomial};
+    ///
+    /// // (x^3 - 2x^2 + 4) / (x - 3) = x^2 + x + 3, remainder 13
+    /// let dividend = Polynomial(vec![
+    ///     Monomial { c: 1.0, e: 3.0 },
+    ///     Monomial { c: -2.0, e: 2.0 },
+    ///     Monomial { c: 4.0, e: 0.0 },
+    /// ]);
+    /// let divisor = Polynomial(vec![
+    ///     Monomial { c: 1.0, e: 1.0 },
+    ///     Monomial { c: -3.0, e: 0.0 },
+    /// ]);
+    /// let (quotient, remainder) = dividend.div_rem(&divisor).unwrap();
+    /// assert_eq!(quotient, Polynomial(vec![
+    ///     Monomial { c: 1.0, e: 2.0 },
+    ///     Monomial { c: 1.0, e: 1.0 },
+    ///     Monomial { c: 3.0, e: 0.0 },
+    /// ]));
+    /// assert_eq!(remainder, Polynomial(vec![Monomial { c: 13.0, e: 0.0 }]));
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// If either polynomial has an exponent of x which is not a non-negative integer, or if the divisor is zero, an error is returned.
+    pub fn div_rem(&self, divisor: &Self) -> Result<(Self, Self), CalcError> {
+        let dividend = coefficients::from_non_negative_polynomial(self)?;
+        let divisor = coefficients::from_non_negative_polynomial(divisor)?;
+        if divisor.is_empty() {
+            return Err(DomainError::DivisionByZero.into());
+        }
+        let (quotient, remainder) = coefficients::div_rem(&dividend, &divisor);
+        Ok((
+            coefficients::to_polynomial(&quotient),
+            coefficients::to_polynomial(&remainder),
+        ))
+    }
+
+    /// Calculates the greatest common divisor of two polynomials with the Euclidean algorithm.
+    ///
+    /// Both polynomials must have non-negative integer exponents. The result is monic (its leading coefficient is `1`), except that the greatest common divisor of two zero polynomials is zero.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{Monomial, Polynomial};
+    ///
+    /// // x^2 - 1 = (x - 1)(x + 1)
+    /// let p1 = Polynomial(vec![
+    ///     Monomial { c: 1.0, e: 2.0 },
+    ///     Monomial { c: -1.0, e: 0.0 },
+    /// ]);
+    /// // 2x^2 - 4x + 2 = 2(x - 1)^2
+    /// let p2 = Polynomial(vec![
+    ///     Monomial { c: 2.0, e: 2.0 },
+    ///     Monomial { c: -4.0, e: 1.0 },
+    ///     Monomial { c: 2.0, e: 0.0 },
+    /// ]);
+    /// assert_eq!(p1.gcd(&p2).unwrap(), Polynomial(vec![
+    ///     Monomial { c: 1.0, e: 1.0 },
+    ///     Monomial { c: -1.0, e: 0.0 },
+    /// ]));
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// If either polynomial has an exponent of x which is not a non-negative integer, an error is returned.
+    pub fn gcd(&self, other: &Self) -> Result<Self, CalcError> {
+        let a = coefficients::from_non_negative_polynomial(self)?;
+        let b = coefficients::from_non_negative_polynomial(other)?;
+        Ok(coefficients::to_polynomial(&coefficients::gcd(&a, &b)))
+    }
+
+    /// Calculates the least common multiple of two polynomials, as their product divided by their greatest common divisor.
+    ///
+    /// Both polynomials must have non-negative integer exponents. The result is monic, except that the least common multiple of zero and any polynomial is zero.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{Monomial, Polynomial};
+    ///
+    /// // x^2 - 1 = (x - 1)(x + 1)
+    /// let p1 = Polynomial(vec![
+    ///     Monomial { c: 1.0, e: 2.0 },
+    ///     Monomial { c: -1.0, e: 0.0 },
+    /// ]);
+    /// // x^2 - 2x + 1 = (x - 1)^2
+    /// let p2 = Polynomial(vec![
+    ///     Monomial { c: 1.0, e: 2.0 },
+    ///     Monomial { c: -2.0, e: 1.0 },
+    ///     Monomial { c: 1.0, e: 0.0 },
+    /// ]);
+    /// // (x - 1)^2 (x + 1) = x^3 - x^2 - x + 1
+    /// assert_eq!(p1.lcm(&p2).unwrap(), Polynomial(vec![
+    ///     Monomial { c: 1.0, e: 3.0 },
+    ///     Monomial { c: -1.0, e: 2.0 },
+    ///     Monomial { c: -1.0, e: 1.0 },
+    ///     Monomial { c: 1.0, e: 0.0 },
+    /// ]));
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// If either polynomial has an exponent of x which is not a non-negative integer, an error is returned.
+    pub fn lcm(&self, other: &Self) -> Result<Self, CalcError> {
+        let a = coefficients::from_non_negative_polynomial(self)?;
+        let b = coefficients::from_non_negative_polynomial(other)?;
+        if a.is_empty() || b.is_empty() {
+            return Ok(Self::new());
+        }
+        let (quotient, _) = coefficients::div_rem(&a, &coefficients::gcd(&a, &b));
+        let product = coefficients::multiply(&quotient, &b);
+        Ok(coefficients::to_polynomial(&coefficients::monic(&product)))
     }
 
+    /// Composes two polynomials, giving `self(inner(x))`.
+    ///
+    /// `self` must have non-negative integer exponents, while `inner` can be any polynomial.
+    /// The composition is calculated with Horner's method, `(...(c_n * inner + c_{n-1}) * inner + ...) + c_0`, which needs one polynomial multiplication per degree of `self`.
+    /// If `inner` is of the form `ax + b`, the cheaper `substitute_affine()` is used instead.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{Monomial, Polynomial};
+    ///
+    /// // p(x) = x^2 + 1, q(x) = x^3 - x
+    /// let p = Polynomial(vec![
+    ///     Monomial { c: 1.0, e: 2.0 },
+    ///     Monomial { c: 1.0, e: 0.0 },
+    /// ]);
+    /// let q = Polynomial(vec![
+    ///     Monomial { c: 1.0, e: 3.0 },
+    ///     Monomial { c: -1.0, e: 1.0 },
+    /// ]);
+    /// // p(q(x)) = x^6 - 2x^4 + x^2 + 1
+    /// assert_eq!(p.compose(&q).unwrap(), Polynomial(vec![
+    ///     Monomial { c: 1.0, e: 6.0 },
+    ///     Monomial { c: -2.0, e: 4.0 },
+    ///     Monomial { c: 1.0, e: 2.0 },
+    ///     Monomial { c: 1.0, e: 0.0 },
+    /// ]));
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// If `self` has an exponent of x which is not a non-negative integer, an error is returned.
+    pub fn compose(&self, inner: &Self) -> Result<Self, CalcError> {
+        let outer = coefficients::from_non_negative_polynomial(self)?;
+        let Ok(inner_coefficients) = coefficients::from_non_negative_polynomial(inner) else {
+            let mut composition = Self::new();
+            for c in outer.iter().rev() {
+                composition = &(&composition * inner) + &Monomial::new(*c, 0_f64);
+            }
+            return Ok(composition);
+        };
+        if inner_coefficients.len() <= 2 {
+            let b = inner_coefficients.first().copied().unwrap_or(0_f64);
+            let a = inner_coefficients.get(1).copied().unwrap_or(0_f64);
+            return self.substitute_affine(a, b);
+        }
+        let mut composition: Vec<f64> = vec![];
+        for c in outer.iter().rev() {
+            composition = coefficients::multiply(&composition, &inner_coefficients);
+            match composition.first_mut() {
+                Some(constant) => *constant += c,
+                None => composition.push(*c),
+            }
+        }
+        Ok(coefficients::to_polynomial(&composition))
+    }
+
+    /// Substitutes `ax + b` for x, giving `self(ax + b)`.
+    ///
+    /// `self` must have non-negative integer exponents.
+    /// The polynomial is first shifted to `self(x + b)` by repeated synthetic division, which builds up the binomial coefficients of `(x + b)^n` by addition as in Pascal's triangle, so that they are exact.
+    /// The coefficient of `x^k` is then multiplied by `a^k`. This takes O(n²) time, without multiplying any polynomials.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{Monomial, Polynomial};
+    ///
+    /// // p(x) = x^3
+    /// let p = Polynomial(vec![Monomial { c: 1.0, e: 3.0 }]);
+    /// // p(2x - 1) = 8x^3 - 12x^2 + 6x - 1
+    /// assert_eq!(p.substitute_affine(2.0, -1.0).unwrap(), Polynomial(vec![
+    ///     Monomial { c: 8.0, e: 3.0 },
+    ///     Monomial { c: -12.0, e: 2.0 },
+    ///     Monomial { c: 6.0, e: 1.0 },
+    ///     Monomial { c: -1.0, e: 0.0 },
+    /// ]));
+    ///
+    /// // Map the interval [0, 10] onto [-1, 1], with x = 5t + 5.
+    /// let q = Polynomial(vec![Monomial { c: 1.0, e: 1.0 }]);
+    /// let mapped = q.substitute_affine(5.0, 5.0).unwrap();
+    /// assert_eq!(mapped.value(-1.0), 0.0);
+    /// assert_eq!(mapped.value(1.0), 10.0);
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// If `self` has an exponent of x which is not a non-negative integer, an error is returned.
+    pub fn substitute_affine(&self, a: f64, b: f64) -> Result<Self, CalcError> {
+        let mut coefficients = coefficients::from_non_negative_polynomial(self)?;
+        coefficients::taylor_shift(&mut coefficients, b);
+        let mut power = 1_f64;
+        for c in &mut coefficients {
+            *c *= power;
+            power *= a;
+        }
+        Ok(coefficients::to_polynomial(&coefficients))
+    }
+
+    /// Calculates the coefficients of the Taylor expansion of the polynomial around `x0`, up to the given order.
+    ///
+    /// The `k`th coefficient is the `k`th derivative at `x0` divided by `k!`, so that the polynomial equals the sum of `coefficient_k * (x - x0)^k`.
+    /// Each monomial `cx^e` contributes `c * binomial(e, k) * x0^(e - k)` to the `k`th coefficient,
+    /// so every coefficient is found in one pass over the monomials, without calculating any derivative polynomials.
+    /// Monomials with fractional or negative exponents have infinite expansions, of which the first `order + 1` coefficients are returned.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{Monomial, Polynomial};
+    ///
+    /// // p(x) = x^3 - 2x = (x - 1)^3 + 3(x - 1)^2 + (x - 1) - 1
+    /// let p = Polynomial(vec![
+    ///     Monomial { c: 1.0, e: 3.0 },
+    ///     Monomial { c: -2.0, e: 1.0 },
+    /// ]);
+    /// assert_eq!(p.taylor_coefficients_at(1.0, 5).unwrap(), vec![-1.0, 1.0, 3.0, 1.0, 0.0, 0.0]);
+    ///
+    /// // sqrt(x) = 2 + (x - 4)/4 - (x - 4)^2/64 + ...
+    /// let q = Polynomial(vec![Monomial { c: 1.0, e: 0.5 }]);
+    /// assert_eq!(q.taylor_coefficients_at(4.0, 2).unwrap(), vec![2.0, 0.25, -1.0 / 64.0]);
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// If a monomial has a fractional exponent and `x0` is negative, or if a coefficient is infinite or `NaN` (as for `x^-1` at `x0 = 0`), an error is returned.
+    pub fn taylor_coefficients_at(&self, x0: f64, order: u32) -> Result<Vec<f64>, CalcError> {
+        let mut coefficients = vec![0_f64; order as usize + 1];
+        for element in &self.0 {
+            let rounded = element.e.round();
+            let is_integer = math_helpers::is_equal_within_tolerance_to(&element.e, &rounded);
+            if !is_integer && x0 < 0_f64 {
+                return Err(DomainError::FractionalPowerOfNegative {
+                    exponent: element.e,
+                }
+                .into());
+            }
+            let e = if is_integer { rounded } else { element.e };
+
+            // c * binomial(e, k), which is 0 for every k beyond a non-negative integer e.
+            let mut scaled_binomial = element.c;
+            for (k, coefficient) in (0..=order).zip(&mut coefficients) {
+                let k = f64::from(k);
+                if k > 0_f64 {
+                    scaled_binomial *= (e - k + 1_f64) / k;
+                }
+                if scaled_binomial == 0_f64 {
+                    break;
+                }
+                *coefficient += scaled_binomial * x0.powf(e - k);
+            }
+        }
+        if let Some(value) = coefficients.iter().find(|c| !c.is_finite()) {
+            return Err(CalcError::NonFinite(*value));
+        }
+        Ok(coefficients)
+    }
+
+    /// Rewrites the polynomial in powers of `(x - x0)`.
+    ///
+    /// The returned polynomial `q` satisfies `q(x - x0) = self(x)`, so its coefficients are the Taylor coefficients of `self` around `x0`.
+    /// This is `self.substitute_affine(1.0, x0)`, and likewise `self` must have non-negative integer exponents.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{Monomial, Polynomial};
+    ///
+    /// // x^2 = (x - 3)^2 + 6(x - 3) + 9
+    /// let p = Polynomial(vec![Monomial { c: 1.0, e: 2.0 }]);
+    /// let q = p.recenter(3.0).unwrap();
+    /// assert_eq!(q, Polynomial(vec![
+    ///     Monomial { c: 1.0, e: 2.0 },
+    ///     Monomial { c: 6.0, e: 1.0 },
+    ///     Monomial { c: 9.0, e: 0.0 },
+    /// ]));
+    /// assert_eq!(q.value(5.0 - 3.0), p.value(5.0));
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// If the polynomial has an exponent of x which is not a non-negative integer, an error is returned.
+    pub fn recenter(&self, x0: f64) -> Result<Self, CalcError> {
+        self.substitute_affine(1_f64, x0)
+    }
+}
+
+impl<T: Scalar> Polynomial<T> {
     /// Calculates the derivative of the polynomial.
     ///
     /// The derivative of a polynomial is the sum of the derivatives of each monomial in the polynomial.
@@ -531,13 +1252,17 @@ impl Polynomial {
     /// 
     /// ## Errors
     /// 
-    /// If two monomials with different exponents are attempted to be combined, an error is returned.
-    pub fn derivative(&self) -> Result<Self, String> {
+    /// Never returns an error, as simplification cannot fail; the `Result` is kept for compatibility.
+    ///
+    /// ## Panics
+    ///
+    /// With `Rational` numbers, panics if a coefficient of the derivative overflows `i64`.
+    pub fn derivative(&self) -> Result<Self, CalcError> {
         let mut elements = vec![];
         for element in &self.0 {
             elements.push(element.derivative());
         }
-        Ok(Self(elements).simplified()?)
+        Self(elements).simplified()
     }
 
     /// Calculates the nth derivative of the polynomial.
@@ -579,8 +1304,12 @@ impl Polynomial {
     /// 
     /// ## Errors
     /// 
-    /// If two monomials with different exponents are attempted to be combined, an error is returned.
-    pub fn nth_derivative(&self, n: u32) -> Result<Self, String> {
+    /// Never returns an error, as simplification cannot fail; the `Result` is kept for compatibility.
+    ///
+    /// ## Panics
+    ///
+    /// With `Rational` numbers, panics if a coefficient of one of the derivatives overflows `i64`.
+    pub fn nth_derivative(&self, n: u32) -> Result<Self, CalcError> {
         let mut new_polynomial = self.clone();
         for _ in 0..n {
             new_polynomial = new_polynomial.derivative()?;
@@ -588,6 +1317,62 @@ impl Polynomial {
         Ok(new_polynomial)
     }
 
+    /// Calculates the antiderivative of the polynomial, using `constant` as the constant of integration.
+    ///
+    /// Each monomial is integrated with the power rule. Terms in `x^-1` are integrated to `ln|x|` and collected into the `log_coefficient` of the returned `Antiderivative`.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{Monomial, Polynomial};
+    ///
+    /// let my_polynomial = Polynomial(vec![
+    ///     Monomial { c: 3.0, e: 2.0 },
+    ///     Monomial { c: 2.0, e: 1.0 },
+    ///     Monomial { c: 1.0, e: 0.0 },
+    /// ]);
+    /// let my_antiderivative = my_polynomial.antiderivative(4.0).unwrap();
+    /// assert_eq!(my_antiderivative.polynomial, Polynomial(vec![
+    ///     Monomial { c: 1.0, e: 3.0 },
+    ///     Monomial { c: 1.0, e: 2.0 },
+    ///     Monomial { c: 1.0, e: 1.0 },
+    ///     Monomial { c: 4.0, e: 0.0 },
+    /// ]));
+    /// assert!(!my_antiderivative.has_log_term());
+    /// ```
+    ///
+    /// The above code does the same as the following mathematical expression:
+    /// ```math
+    /// f(x) = 3x^2 + 2x + 1
+    /// F(x) = x^3 + x^2 + x + 4
+    /// ```
+    ///
+    /// `antiderivative()` itself calls `simplified()` on the power-rule part before returning the result.
+    ///
+    /// ## Errors
+    ///
+    /// If two monomials with different exponents are attempted to be combined, an error is returned.
+    ///
+    /// ## Panics
+    ///
+    /// With `Rational` numbers, panics if a coefficient or exponent of the antiderivative overflows `i64`.
+    pub fn antiderivative(&self, constant: T) -> Result<Antiderivative<T>, CalcError> {
+        let mut elements = vec![];
+        let mut log_coefficient = T::zero();
+        for element in &self.0 {
+            if element.e.is_equal_within_tolerance_to(&-T::one()) {
+                log_coefficient = log_coefficient + element.c.clone();
+            } else {
+                elements.push(element.antiderivative()?);
+            }
+        }
+        elements.push(Monomial::new(constant, T::zero()));
+        let polynomial = Self(elements).simplified()?;
+        Ok(Antiderivative {
+            polynomial,
+            log_coefficient,
+        })
+    }
+
     /// Checks if the polynomial is equal to another polynomial within a certain tolerance.
     ///
     /// This function is to overcome floating point arithmetic errors.
@@ -607,47 +1392,65 @@ impl Polynomial {
     /// assert!(my_polynomial1.is_equal_within_tolerance_to(my_polynomial2).unwrap());
     /// ```
     ///
-    /// The above code will return `true` because the two polynomials are equal within the tolerance defined in the `math_helpers` module.
+    /// The above code will return `true` because the two polynomials are equal within the crate-wide default `Tolerance`.
     /// 
     /// ## Errors
     /// 
-    /// If two monomials with different exponents are attempted to be combined during simplification, an error is returned.
-    pub fn is_equal_within_tolerance_to(&self, other: Self) -> Result<bool, String> {
-        let simplified_self = self.simplified()?;
-        let simplified_other = other.simplified()?;
-        if simplified_self.0.len() != simplified_other.0.len() {
-            return Ok(false);
-        }
-        for (element1, element2) in simplified_self.0.iter().zip(simplified_other.0.iter()) {
-            if math_helpers::is_equal_within_tolerance_to(&element1.c, &element2.c) == false
-                || math_helpers::is_equal_within_tolerance_to(&element1.e, &element2.e) == false
-            {
-                return Ok(false);
-            }
-        }
-        Ok(true)
+    /// Never returns an error; the `Result` is kept for compatibility with earlier versions, in which simplification could fail.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn is_equal_within_tolerance_to(&self, other: Self) -> Result<bool, CalcError> {
+        Ok(self.is_equal_within(&other, T::default_tolerance()))
+    }
+
+    /// Checks if the polynomial is equal to another polynomial within the given tolerance, after simplifying both with it.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{Monomial, Polynomial, Tolerance};
+    ///
+    /// let my_polynomial1 = Polynomial(vec![Monomial { c: 1e-14, e: 1.0 }]);
+    /// let my_polynomial2 = Polynomial(vec![Monomial { c: 2e-14, e: 1.0 }]);
+    /// assert!(my_polynomial1.is_equal_within(&my_polynomial2, Tolerance::Absolute(1e-10)));
+    /// assert!(!my_polynomial1.is_equal_within(&my_polynomial2, Tolerance::Relative(1e-10)));
+    /// ```
+    #[must_use]
+    pub fn is_equal_within(&self, other: &Self, tolerance: Tolerance) -> bool {
+        let simplified_self = self.simplified_with_tolerance(tolerance);
+        let simplified_other = other.simplified_with_tolerance(tolerance);
+        simplified_self.0.len() == simplified_other.0.len()
+            && simplified_self
+                .0
+                .iter()
+                .zip(simplified_other.0.iter())
+                .all(|(element1, element2)| element1.is_equal_within(element2, tolerance))
     }
+}
 
-    /// Checks whether the values of a given interval of a polynomial overall is increasing, decreasing, staying constant, or is undefined.
+impl Polynomial {
+    /// Checks whether the values of a given interval of a polynomial overall is increasing, decreasing, staying constant, mixed, or is undefined, as a `Trend`.
     /// The interval is defined by the start and end values.
     ///
     /// #### Example
     /// ```rust
-    /// use calcucalc::{Monomial, Polynomial};
+    /// use calcucalc::{Monomial, Polynomial, Trend};
     ///
     /// let my_polynomial = Polynomial(vec![
     ///     Monomial { c: 1.0, e: 2.0 },
     ///     Monomial { c: -2.0, e: 1.0 },
     ///     Monomial { c: 1.0, e: 0.0 },
     /// ]);
-    /// assert_eq!(my_polynomial.trend_over_interval(-1.0, 1.0), "decreasing");
-    /// assert_eq!(my_polynomial.trend_over_interval(1.0, 2.0), "increasing");
-    /// assert_eq!(my_polynomial.trend_over_interval(-1.0, 0.0), "decreasing");
-    /// assert_eq!(my_polynomial.trend_over_interval(0.0, 2.0), "constant");
+    /// assert_eq!(my_polynomial.trend_over_interval(-1.0, 1.0), Trend::Decreasing);
+    /// assert_eq!(my_polynomial.trend_over_interval(1.0, 2.0), Trend::Increasing);
+    /// assert_eq!(my_polynomial.trend_over_interval(-1.0, 0.0), Trend::Decreasing);
+    /// assert_eq!(my_polynomial.trend_over_interval(0.0, 2.0), Trend::Mixed);
     /// ```
     ///
+    /// If the polynomial decreases and then increases within the interval, as above between `0` and `2`, the trend is `Trend::Mixed`. Use `monotonicity()` to find out where it increases and decreases.
+    /// For polynomials whose derivative has fractional exponents, the critical points cannot be found, so only the values at the start and end of the interval are compared.
+    ///
     /// While it is recommended to order the start and end x-values in ascending order, this function will automatically swap them if they are not.
-    pub fn trend_over_interval(&self, start: f64, end: f64) -> String {
+    #[must_use]
+    pub fn trend_over_interval(&self, start: f64, end: f64) -> Trend {
         // Validate the start and end x-values are in the correct order,
         // and swap them if they are not.
         let mut start_x = start;
@@ -658,23 +1461,32 @@ impl Polynomial {
 
         let start_value = self.value(start_x);
         let end_value = self.value(end_x);
+        if start_value.is_nan() || end_value.is_nan() {
+            return Trend::Undefined;
+        }
+        if let Ok(intervals) = self.monotonicity(start_x, end_x) {
+            return match intervals.as_slice() {
+                [interval] => interval.trend,
+                _ => Trend::Mixed,
+            };
+        }
+
         if start_value < end_value {
-            "increasing".to_string()
+            Trend::Increasing
         } else if start_value > end_value {
-            "decreasing".to_string()
-        } else if start_value == end_value {
-            "constant".to_string()
+            Trend::Decreasing
         } else {
-            "undefined".to_string()
+            Trend::Constant
         }
     }
 
-    /// Checks whether a given interval of a polynomial is "concave up", "concave down", or "undefined".
-    /// The interval is defined by the start and end values. This function does not take into account anything in between. In other words, it only tells you whether or not the **overall** concavity of the polynomial is up or down over the interval. It says nothing about the concavity at any specific point within the interval.
+    /// Checks whether a given interval of a polynomial is "concave up", "concave down", or "undefined", as a `Concavity`.
+    /// The interval is defined by the start and end values. The concavity is only "concave up" or "concave down" if it holds over the whole interval; if it flips somewhere in between, it is "undefined". Use `concavity()` to find out where it flips.
+    /// For polynomials whose second derivative has fractional exponents, its roots cannot be found, so only the second derivative at the start and end of the interval is checked.
     ///
     /// #### Example
     /// ```rust
-    /// use calcucalc::{Monomial, Polynomial};
+    /// use calcucalc::{Concavity, Monomial, Polynomial};
     ///
     /// let my_polynomial = Polynomial(vec![
     ///     Monomial { c: 1.0, e: 3.0 },
@@ -682,16 +1494,16 @@ impl Polynomial {
     ///     Monomial { c: -2.0, e: 1.0 },
     ///     Monomial { c: 1.0, e: 0.0 },
     /// ]);
-    /// assert_eq!(my_polynomial.concavity_over_interval(-2.0, -1.0).unwrap(), "concave down");
-    /// assert_eq!(my_polynomial.concavity_over_interval(-1.0, 0.0).unwrap(), "undefined");
-    /// assert_eq!(my_polynomial.concavity_over_interval(0.0, 1.0).unwrap(), "concave up");
+    /// assert_eq!(my_polynomial.concavity_over_interval(-2.0, -1.0).unwrap(), Concavity::ConcaveDown);
+    /// assert_eq!(my_polynomial.concavity_over_interval(-1.0, 0.0).unwrap(), Concavity::Undefined);
+    /// assert_eq!(my_polynomial.concavity_over_interval(0.0, 1.0).unwrap(), Concavity::ConcaveUp);
     ///
     /// ```
     /// 
     /// ## Errors
     /// 
     /// If the second derivative cannot be calculated, an error is returned.
-    pub fn concavity_over_interval(&self, start: f64, end: f64) -> Result<String, String> {
+    pub fn concavity_over_interval(&self, start: f64, end: f64) -> Result<Concavity, CalcError> {
         // Validate the start and end x-values are in the correct order,
         // and swap them if they are not.
         let mut start_x = start;
@@ -700,22 +1512,36 @@ impl Polynomial {
             std::mem::swap(&mut start_x, &mut end_x);
         }
 
-        // Calculate the second derivative of the polynomial
-        let second_derivative = self.nth_derivative(2)?;
+        if let Ok(analysis) = self.concavity(start_x, end_x) {
+            return Ok(match analysis.intervals.as_slice() {
+                [interval] => interval.concavity,
+                _ => Concavity::Undefined,
+            });
+        }
 
         // Calculate the second derivative at the start and end x-values
+        let second_derivative = self.nth_derivative(2)?;
         let start_value = second_derivative.value(start_x);
         let end_value = second_derivative.value(end_x);
 
         if start_value > 0.0 && end_value > 0.0 {
-            Ok("concave up".to_string())
+            Ok(Concavity::ConcaveUp)
         } else if start_value < 0.0 && end_value < 0.0 {
-            Ok("concave down".to_string())
+            Ok(Concavity::ConcaveDown)
         } else {
-            Ok("undefined".to_string())
+            Ok(Concavity::Undefined)
         }
     }
 }
 
+/// Orders exponents of x from largest to smallest, placing exponents which cannot be compared (such as `NaN`) last.
+fn descending_exponents<T: PartialOrd>(a: &T, b: &T) -> std::cmp::Ordering {
+    let comparable = |x: &T| x.partial_cmp(x).is_some();
+    match (comparable(a), comparable(b)) {
+        (true, true) => b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal),
+        (a_comparable, b_comparable) => b_comparable.cmp(&a_comparable),
+    }
+}
+
 #[cfg(test)]
 mod tests;
@@ -1,5 +1,8 @@
+use crate::Tolerance;
 
-/// Checks if two f64 values are equal within a specified tolerance.
+/// Checks if two f64 values are equal within `Tolerance::DEFAULT`, an absolute tolerance of `1e-10`.
+///
+/// This is a fixed tolerance, which is not affected by `Tolerance::set_global()`, so algorithms which use it behave the same whatever the crate-wide default is.
 /// 
 /// ```rust
 /// use calcucalc::math_helpers::is_equal_within_tolerance_to;
@@ -10,6 +13,5 @@
 /// ```
 #[must_use]
 pub fn is_equal_within_tolerance_to(a: &f64, b: &f64) -> bool {
-    let tolerance = 1e-10;
-    (a - b).abs() < tolerance
+    Tolerance::DEFAULT.is_equal(*a, *b)
 }
\ No newline at end of file
@@ -0,0 +1,177 @@
+//! Fast multiplication of coefficient vectors, for polynomials with many terms.
+//!
+//! Multiplying every coefficient of one polynomial by every coefficient of the other takes O(nm) time.
+//! Above a threshold, Karatsuba's algorithm takes O(n^1.59) time, and above a higher one, multiplying with the fast Fourier transform takes O(n log n) time.
+//! The FFT rounds every coefficient of the product to within about `n * f64::EPSILON` times the largest one, so it is only used where Karatsuba's algorithm would be too slow.
+
+use std::f64::consts::PI;
+
+use crate::{Complex, Monomial, Polynomial, coefficients};
+
+/// Below this many coefficients in the shorter polynomial, the naive algorithm is the fastest.
+const KARATSUBA_THRESHOLD: usize = 32;
+
+/// From this many coefficients in the shorter polynomial, the FFT is used.
+const FFT_THRESHOLD: usize = 4096;
+
+/// Multiplies two coefficient vectors, choosing the algorithm from their lengths.
+pub(crate) fn multiply(a: &[f64], b: &[f64]) -> Vec<f64> {
+    let shorter = a.len().min(b.len());
+    if shorter < KARATSUBA_THRESHOLD {
+        naive(a, b)
+    } else if shorter < FFT_THRESHOLD {
+        karatsuba(a, b)
+    } else {
+        fft_multiply(a, b)
+    }
+}
+
+/// Multiplies two polynomials with integer exponents as coefficient vectors, or returns `None` if they are too small or too sparse for it to be worthwhile.
+///
+/// The product is simplified, just like the result of multiplying every pair of monomials.
+pub(crate) fn multiply_polynomials(a: &Polynomial, b: &Polynomial) -> Option<Polynomial> {
+    if a.0.len().min(b.0.len()) < KARATSUBA_THRESHOLD
+        || !coefficients::is_dense(a)
+        || !coefficients::is_dense(b)
+    {
+        return None;
+    }
+    let (a_coefficients, a_shift) = coefficients::from_polynomial(a).ok()?;
+    let (b_coefficients, b_shift) = coefficients::from_polynomial(b).ok()?;
+    let shift = f64::from(a_shift) + f64::from(b_shift);
+    let product = coefficients::to_polynomial(&multiply(&a_coefficients, &b_coefficients));
+    Some(
+        Polynomial(
+            product
+                .0
+                .into_iter()
+                .map(|element| Monomial::new(element.c, element.e + shift))
+                .collect(),
+        )
+        .eliminate_zero_coefficients(),
+    )
+}
+
+/// Multiplies every coefficient of one vector by every coefficient of the other.
+fn naive(a: &[f64], b: &[f64]) -> Vec<f64> {
+    if a.is_empty() || b.is_empty() {
+        return vec![];
+    }
+    let mut product = vec![0_f64; a.len() + b.len() - 1];
+    for (i, x) in a.iter().enumerate() {
+        for (j, y) in b.iter().enumerate() {
+            product[i + j] += x * y;
+        }
+    }
+    product
+}
+
+/// Multiplies with Karatsuba's algorithm, which splits both vectors in two and needs three half-sized products instead of four.
+fn karatsuba(a: &[f64], b: &[f64]) -> Vec<f64> {
+    if a.len().min(b.len()) < KARATSUBA_THRESHOLD {
+        return naive(a, b);
+    }
+
+    // a = a_low + x^half * a_high, and likewise for b.
+    let half = a.len().max(b.len()) / 2;
+    let (a_low, a_high) = a.split_at(half.min(a.len()));
+    let (b_low, b_high) = b.split_at(half.min(b.len()));
+
+    let low = karatsuba(a_low, b_low);
+    let high = karatsuba(a_high, b_high);
+    // (a_low + a_high)(b_low + b_high) - low - high = a_low * b_high + a_high * b_low
+    let mut middle = karatsuba(&sum(a_low, a_high), &sum(b_low, b_high));
+    add_scaled(&mut middle, 0, &low, -1_f64);
+    add_scaled(&mut middle, 0, &high, -1_f64);
+
+    let mut product = vec![0_f64; a.len() + b.len() - 1];
+    add_scaled(&mut product, 0, &low, 1_f64);
+    add_scaled(&mut product, half, &middle, 1_f64);
+    add_scaled(&mut product, 2 * half, &high, 1_f64);
+    product
+}
+
+/// Adds two coefficient vectors.
+fn sum(a: &[f64], b: &[f64]) -> Vec<f64> {
+    let mut result = vec![0_f64; a.len().max(b.len())];
+    add_scaled(&mut result, 0, a, 1_f64);
+    add_scaled(&mut result, 0, b, 1_f64);
+    result
+}
+
+/// Adds `factor * values` to `result`, starting at the given index.
+///
+/// Values beyond the end of `result` are left out, as they are always `0` in a product which fits in it.
+fn add_scaled(result: &mut [f64], offset: usize, values: &[f64], factor: f64) {
+    for (target, value) in result.iter_mut().skip(offset).zip(values) {
+        *target += factor * value;
+    }
+}
+
+/// Multiplies by transforming both vectors with the FFT, multiplying them pointwise, and transforming the result back.
+fn fft_multiply(a: &[f64], b: &[f64]) -> Vec<f64> {
+    let product_length = a.len() + b.len() - 1;
+    let size = product_length.next_power_of_two();
+    let mut a_transform = to_complex(a, size);
+    let mut b_transform = to_complex(b, size);
+    fft(&mut a_transform, false);
+    fft(&mut b_transform, false);
+    for (x, y) in a_transform.iter_mut().zip(&b_transform) {
+        *x = *x * *y;
+    }
+    fft(&mut a_transform, true);
+    a_transform[..product_length].iter().map(|z| z.re).collect()
+}
+
+/// Converts a coefficient vector into complex numbers, padded with zeroes to the given size.
+fn to_complex(values: &[f64], size: usize) -> Vec<Complex> {
+    let mut result: Vec<Complex> = values.iter().map(|value| Complex::from(*value)).collect();
+    result.resize(size, Complex::new(0_f64, 0_f64));
+    result
+}
+
+/// The iterative radix-2 fast Fourier transform, in place. The length must be a power of two.
+///
+/// The inverse transform is scaled by `1/n`, so that it undoes the forward transform.
+#[allow(clippy::cast_precision_loss)]
+fn fft(values: &mut [Complex], inverse: bool) {
+    let n = values.len();
+
+    // Reorder the values by the bit-reversal of their indices.
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            values.swap(i, j);
+        }
+    }
+
+    let direction = if inverse { -1_f64 } else { 1_f64 };
+    let mut length = 2;
+    while length <= n {
+        // Each root of unity is calculated directly, so that rounding errors do not build up.
+        let roots: Vec<Complex> = (0..length / 2)
+            .map(|k| Complex::from_polar(1_f64, direction * 2_f64 * PI * k as f64 / length as f64))
+            .collect();
+        for start in (0..n).step_by(length) {
+            for (k, root) in roots.iter().enumerate() {
+                let even = values[start + k];
+                let odd = values[start + k + length / 2] * *root;
+                values[start + k] = even + odd;
+                values[start + k + length / 2] = even - odd;
+            }
+        }
+        length <<= 1;
+    }
+
+    if inverse {
+        for value in values.iter_mut() {
+            *value = *value * (1_f64 / n as f64);
+        }
+    }
+}
@@ -0,0 +1,132 @@
+//! Fast multipoint evaluation of coefficient vectors, for evaluating polynomials of high degree at many points.
+//!
+//! Horner's method takes n multiplications and additions per point for a polynomial of degree n.
+//! When there are many more points than coefficients, the points are sorted into groups of neighbouring points,
+//! and the polynomial is re-centred at the middle of each group with a Taylor shift.
+//! Close to its centre, the terms of the re-centred polynomial fall off quickly, so the terms which are too small to change any value in the group can be left out,
+//! and each point of the group only needs as many multiplications and additions as there are terms left.
+//! Groups whose points are too far apart for that are split in two, or evaluated with Horner's method if they are already small.
+//!
+//! Remainder trees, which divide the polynomial by products of `(x - point)`, are asymptotically faster still, but they are not used:
+//! the coefficients of those products grow exponentially for real points, and in floating point arithmetic the values lose all accuracy beyond a few dozen points.
+//! The re-centred values are as accurate as Horner's method at the point of their group which is farthest from zero,
+//! and groups are split until that is within `MAX_SCALE_RATIO` times the accuracy at their point nearest to zero.
+
+use crate::coefficients;
+
+/// From this many coefficients, polynomials are evaluated by re-centring them, if there are enough points.
+pub(crate) const MULTIPOINT_THRESHOLD: usize = 256;
+
+/// A group is split in two if the rounding errors at its farthest point from zero could be more than this many times those at its nearest point.
+const MAX_SCALE_RATIO: f64 = 16_f64;
+
+/// Each group has this many points per coefficient, so that the Taylor shift for each group takes little time compared with evaluating its points.
+pub(crate) const POINTS_PER_COEFFICIENT: usize = 4;
+
+/// A group is evaluated with Horner's method if its re-centred polynomial would need more than one term for every this many points.
+const POINTS_PER_TERM: usize = 16;
+
+/// Evaluates the polynomial at many points by re-centring it at groups of neighbouring points, writing the values into `values`.
+pub(crate) fn evaluate(coefficients: &[f64], xs: &[f64], values: &mut [f64]) {
+    let mut order = vec![];
+    for (i, x) in xs.iter().enumerate() {
+        if x.is_finite() {
+            order.push(i);
+        } else {
+            values[i] = coefficients::evaluate(coefficients, *x);
+        }
+    }
+    order.sort_by(|&a, &b| xs[a].total_cmp(&xs[b]));
+    for group in order.chunks(POINTS_PER_COEFFICIENT * coefficients.len()) {
+        evaluate_group(coefficients, xs, values, group);
+    }
+}
+
+/// Evaluates the polynomial at a group of points, given by their indices in ascending order of x.
+///
+/// The rounding errors of the re-centred polynomial are on the scale of those of Horner's method at the point of the group farthest from zero.
+/// Groups where that is much larger than at the point nearest to zero are split in two, and the smallest of them are evaluated with Horner's method instead.
+fn evaluate_group(coefficients: &[f64], xs: &[f64], values: &mut [f64], group: &[usize]) {
+    let (Some(&first), Some(&last)) = (group.first(), group.last()) else {
+        return;
+    };
+    let center = xs[first] / 2_f64 + xs[last] / 2_f64;
+    let radius = (xs[last] - center).max(center - xs[first]);
+    let nearest = if xs[first] <= 0_f64 && 0_f64 <= xs[last] {
+        0_f64
+    } else {
+        xs[first].abs().min(xs[last].abs())
+    };
+    let scale = absolute_value(coefficients, center.abs() + radius);
+
+    let shifted = if scale <= MAX_SCALE_RATIO * absolute_value(coefficients, nearest) {
+        taylor_coefficients(
+            coefficients,
+            center,
+            radius,
+            scale,
+            group.len() / POINTS_PER_TERM,
+        )
+    } else if group.len() >= 2 * coefficients.len() {
+        let (left, right) = group.split_at(group.len() / 2);
+        evaluate_group(coefficients, xs, values, left);
+        evaluate_group(coefficients, xs, values, right);
+        return;
+    } else {
+        None
+    };
+    let (coefficients, center) = match &shifted {
+        Some(shifted) => (shifted.as_slice(), center),
+        None => (coefficients, 0_f64),
+    };
+    let points: Vec<f64> = group.iter().map(|&i| xs[i] - center).collect();
+    let mut group_values = vec![0_f64; group.len()];
+    coefficients::evaluate_blocks(coefficients, &points, &mut group_values);
+    for (&i, value) in group.iter().zip(group_values) {
+        values[i] = value;
+    }
+}
+
+/// The sum of the absolute values of the terms at x, which is the scale of the rounding errors of Horner's method there.
+fn absolute_value(coefficients: &[f64], x: f64) -> f64 {
+    coefficients
+        .iter()
+        .rev()
+        .fold(0_f64, |sum, c| sum * x + c.abs())
+}
+
+/// The coefficients of the polynomial re-centred at `center`, up to the last one which is needed within `radius` of it,
+/// or `None` if more than `max_terms` are needed.
+///
+/// The coefficients are found one at a time by synthetic division by `(x - center)`, as in `coefficients::taylor_shift()`.
+/// Alongside them, the same is done for the absolute values of the coefficients at `|center|`, and the quotient of that bounds the sizes of the terms which have not been found yet.
+/// They stop once those terms add up to less than `f64::EPSILON` times `scale`, the sum of the absolute values of the terms at the farthest point from zero.
+fn taylor_coefficients(
+    coefficients: &[f64],
+    center: f64,
+    radius: f64,
+    scale: f64,
+    max_terms: usize,
+) -> Option<Vec<f64>> {
+    if !scale.is_finite() {
+        return None;
+    }
+
+    let mut quotient = coefficients.to_vec();
+    let mut bound: Vec<f64> = coefficients.iter().map(|c| c.abs()).collect();
+    let mut power = radius;
+    let n = coefficients.len();
+    for j in 0..max_terms.min(n) {
+        for k in (j..n - 1).rev() {
+            quotient[k] += center * quotient[k + 1];
+            bound[k] += center.abs() * bound[k + 1];
+        }
+        let remaining = power * absolute_value(&bound[j + 1..], center.abs() + radius);
+        if remaining <= f64::EPSILON * scale {
+            quotient.truncate(j + 1);
+            return Some(quotient);
+        }
+        power *= radius;
+    }
+    None
+}
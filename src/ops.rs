@@ -0,0 +1,327 @@
+//! Arithmetic operators for monomials and polynomials.
+//!
+//! Polynomial sums, differences and products are simplified, exactly like the results of `add_polynomial()` and `multiply_polynomial()`.
+//! The operators are implemented for both owned values and references, so that operands can be reused without cloning.
+//! Operators between monomials and polynomials work for any `Scalar`, while operators with a plain number work for `f64`.
+//! With `Rational` numbers, the operators panic if a result overflows `i64`, like the methods they call.
+
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{Monomial, Polynomial, Scalar};
+
+/// `simplified()` never returns an error, and only returns a `Result` for compatibility.
+const SIMPLIFICATION_CANNOT_FAIL: &str = "simplifying a polynomial cannot fail";
+
+/// Implements a binary operator for the owned and mixed owned/borrowed combinations of operands, in terms of the implementation for two references.
+macro_rules! forward_binop {
+    ($Trait:ident, $method:ident, $Lhs:ty, $Rhs:ty, $Output:ty) => {
+        impl<T: Scalar> $Trait<$Rhs> for $Lhs {
+            type Output = $Output;
+
+            fn $method(self, rhs: $Rhs) -> $Output {
+                (&self).$method(&rhs)
+            }
+        }
+
+        impl<T: Scalar> $Trait<&$Rhs> for $Lhs {
+            type Output = $Output;
+
+            fn $method(self, rhs: &$Rhs) -> $Output {
+                (&self).$method(rhs)
+            }
+        }
+
+        impl<T: Scalar> $Trait<$Rhs> for &$Lhs {
+            type Output = $Output;
+
+            fn $method(self, rhs: $Rhs) -> $Output {
+                self.$method(&rhs)
+            }
+        }
+    };
+}
+
+/// Implements an assigning operator for owned and borrowed right-hand sides, in terms of the binary operator for two references.
+macro_rules! forward_assign {
+    ($Trait:ident, $method:ident, $op:ident, $Lhs:ty, $Rhs:ty) => {
+        impl<T: Scalar> $Trait<&$Rhs> for $Lhs {
+            fn $method(&mut self, rhs: &$Rhs) {
+                *self = (&*self).$op(rhs);
+            }
+        }
+
+        impl<T: Scalar> $Trait<$Rhs> for $Lhs {
+            fn $method(&mut self, rhs: $Rhs) {
+                *self = (&*self).$op(&rhs);
+            }
+        }
+    };
+}
+
+/// Adds two polynomials, like `add_polynomial()`.
+///
+/// #### Example
+/// ```rust
+/// use calcucalc::{Monomial, Polynomial};
+///
+/// let a: Polynomial = "x + 1".parse().unwrap();
+/// let b: Polynomial = "x - 1".parse().unwrap();
+/// let c = Monomial::new(2.0, 1.0);
+///
+/// assert_eq!(&a * &b, "x^2 - 1".parse().unwrap());
+/// assert_eq!(&a - &b, "2".parse().unwrap());
+/// assert_eq!((&a + &c) * 3.0, "9x + 3".parse().unwrap());
+/// assert_eq!(-(a / 2.0), "-0.5x - 0.5".parse().unwrap());
+/// ```
+impl<T: Scalar> Add for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn add(self, rhs: Self) -> Polynomial<T> {
+        self.add_polynomial(rhs.clone())
+            .expect(SIMPLIFICATION_CANNOT_FAIL)
+    }
+}
+
+impl<T: Scalar> Sub for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn sub(self, rhs: Self) -> Polynomial<T> {
+        self + &(-rhs)
+    }
+}
+
+impl<T: Scalar> Mul for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn mul(self, rhs: Self) -> Polynomial<T> {
+        self.multiply_polynomial(rhs.clone())
+            .expect(SIMPLIFICATION_CANNOT_FAIL)
+    }
+}
+
+forward_binop!(Add, add, Polynomial<T>, Polynomial<T>, Polynomial<T>);
+forward_binop!(Sub, sub, Polynomial<T>, Polynomial<T>, Polynomial<T>);
+forward_binop!(Mul, mul, Polynomial<T>, Polynomial<T>, Polynomial<T>);
+forward_assign!(AddAssign, add_assign, add, Polynomial<T>, Polynomial<T>);
+forward_assign!(SubAssign, sub_assign, sub, Polynomial<T>, Polynomial<T>);
+forward_assign!(MulAssign, mul_assign, mul, Polynomial<T>, Polynomial<T>);
+
+impl<T: Scalar> Add<&Monomial<T>> for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn add(self, rhs: &Monomial<T>) -> Polynomial<T> {
+        self + &Polynomial(vec![rhs.clone()])
+    }
+}
+
+impl<T: Scalar> Sub<&Monomial<T>> for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn sub(self, rhs: &Monomial<T>) -> Polynomial<T> {
+        self + &(-rhs)
+    }
+}
+
+impl<T: Scalar> Mul<&Monomial<T>> for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn mul(self, rhs: &Monomial<T>) -> Polynomial<T> {
+        self * &Polynomial(vec![rhs.clone()])
+    }
+}
+
+forward_binop!(Add, add, Polynomial<T>, Monomial<T>, Polynomial<T>);
+forward_binop!(Sub, sub, Polynomial<T>, Monomial<T>, Polynomial<T>);
+forward_binop!(Mul, mul, Polynomial<T>, Monomial<T>, Polynomial<T>);
+forward_assign!(AddAssign, add_assign, add, Polynomial<T>, Monomial<T>);
+forward_assign!(SubAssign, sub_assign, sub, Polynomial<T>, Monomial<T>);
+forward_assign!(MulAssign, mul_assign, mul, Polynomial<T>, Monomial<T>);
+
+impl<T: Scalar> Neg for &Polynomial<T> {
+    type Output = Polynomial<T>;
+
+    fn neg(self) -> Polynomial<T> {
+        Polynomial(self.0.iter().map(Neg::neg).collect())
+    }
+}
+
+impl<T: Scalar> Neg for Polynomial<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        -&self
+    }
+}
+
+/// Multiplies every coefficient by a scalar. The result is simplified, so multiplying by `0` gives the empty polynomial.
+impl Mul<f64> for &Polynomial {
+    type Output = Polynomial;
+
+    fn mul(self, rhs: f64) -> Polynomial {
+        Polynomial(self.0.iter().map(|element| element * rhs).collect())
+            .simplified()
+            .expect(SIMPLIFICATION_CANNOT_FAIL)
+    }
+}
+
+impl Mul<f64> for Polynomial {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self {
+        &self * rhs
+    }
+}
+
+impl Mul<Polynomial> for f64 {
+    type Output = Polynomial;
+
+    fn mul(self, rhs: Polynomial) -> Polynomial {
+        &rhs * self
+    }
+}
+
+impl Mul<&Polynomial> for f64 {
+    type Output = Polynomial;
+
+    fn mul(self, rhs: &Polynomial) -> Polynomial {
+        rhs * self
+    }
+}
+
+/// Divides every coefficient by a scalar. The result is simplified.
+impl Div<f64> for &Polynomial {
+    type Output = Polynomial;
+
+    fn div(self, rhs: f64) -> Polynomial {
+        Polynomial(self.0.iter().map(|element| element / rhs).collect())
+            .simplified()
+            .expect(SIMPLIFICATION_CANNOT_FAIL)
+    }
+}
+
+impl Div<f64> for Polynomial {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self {
+        &self / rhs
+    }
+}
+
+impl MulAssign<f64> for Polynomial {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = &*self * rhs;
+    }
+}
+
+impl DivAssign<f64> for Polynomial {
+    fn div_assign(&mut self, rhs: f64) {
+        *self = &*self / rhs;
+    }
+}
+
+/// Monomials with different exponents cannot be added into a single monomial, so the sum of two monomials is a polynomial.
+impl<T: Scalar> Add for &Monomial<T> {
+    type Output = Polynomial<T>;
+
+    fn add(self, rhs: Self) -> Polynomial<T> {
+        &Polynomial(vec![self.clone()]) + rhs
+    }
+}
+
+/// The difference of two monomials is a polynomial, as with addition.
+impl<T: Scalar> Sub for &Monomial<T> {
+    type Output = Polynomial<T>;
+
+    fn sub(self, rhs: Self) -> Polynomial<T> {
+        &Polynomial(vec![self.clone()]) - rhs
+    }
+}
+
+impl<T: Scalar> Mul for &Monomial<T> {
+    type Output = Monomial<T>;
+
+    fn mul(self, rhs: Self) -> Monomial<T> {
+        self.multiply_monomial(rhs)
+    }
+}
+
+forward_binop!(Add, add, Monomial<T>, Monomial<T>, Polynomial<T>);
+forward_binop!(Sub, sub, Monomial<T>, Monomial<T>, Polynomial<T>);
+forward_binop!(Mul, mul, Monomial<T>, Monomial<T>, Monomial<T>);
+forward_assign!(MulAssign, mul_assign, mul, Monomial<T>, Monomial<T>);
+
+impl<T: Scalar> Neg for &Monomial<T> {
+    type Output = Monomial<T>;
+
+    fn neg(self) -> Monomial<T> {
+        Monomial::new(-self.c.clone(), self.e.clone())
+    }
+}
+
+impl<T: Scalar> Neg for Monomial<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        -&self
+    }
+}
+
+impl Mul<f64> for &Monomial {
+    type Output = Monomial;
+
+    fn mul(self, rhs: f64) -> Monomial {
+        Monomial::new(self.c * rhs, self.e)
+    }
+}
+
+impl Mul<f64> for Monomial {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self {
+        &self * rhs
+    }
+}
+
+impl Mul<Monomial> for f64 {
+    type Output = Monomial;
+
+    fn mul(self, rhs: Monomial) -> Monomial {
+        &rhs * self
+    }
+}
+
+impl Mul<&Monomial> for f64 {
+    type Output = Monomial;
+
+    fn mul(self, rhs: &Monomial) -> Monomial {
+        rhs * self
+    }
+}
+
+impl Div<f64> for &Monomial {
+    type Output = Monomial;
+
+    fn div(self, rhs: f64) -> Monomial {
+        Monomial::new(self.c / rhs, self.e)
+    }
+}
+
+impl Div<f64> for Monomial {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self {
+        &self / rhs
+    }
+}
+
+impl MulAssign<f64> for Monomial {
+    fn mul_assign(&mut self, rhs: f64) {
+        self.c *= rhs;
+    }
+}
+
+impl DivAssign<f64> for Monomial {
+    fn div_assign(&mut self, rhs: f64) {
+        self.c /= rhs;
+    }
+}
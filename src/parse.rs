@@ -0,0 +1,297 @@
+//! Parsing monomials and polynomials from strings such as `3x^2 - 2.5x + 1`.
+//!
+//! The accepted syntax is a sum of terms, where each term is an optional coefficient followed by an optional power of x:
+//!
+//! | Input | Meaning |
+//! | --- | --- |
+//! | `3x^2`, `3*x^2`, `3x**2` | `Monomial { c: 3.0, e: 2.0 }` |
+//! | `-x^-1`, `-x^(-1)` | `Monomial { c: -1.0, e: -1.0 }` |
+//! | `x^(1/2)`, `x^0.5` | `Monomial { c: 1.0, e: 0.5 }` |
+//! | `2.5x` | `Monomial { c: 2.5, e: 1.0 }` |
+//! | `1e3` | `Monomial { c: 1000.0, e: 0.0 }` |
+//!
+//! Whitespace between tokens is ignored.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Monomial, Polynomial};
+
+/// An error encountered while parsing a monomial or polynomial from a string.
+///
+/// #### Example
+/// ```rust
+/// use calcucalc::{ParseError, ParseErrorKind, Polynomial};
+///
+/// let error = "3x^2 + + 1".parse::<Polynomial>().unwrap_err();
+/// assert_eq!(error, ParseError { position: 7, kind: ParseErrorKind::UnexpectedCharacter('+') });
+/// assert_eq!(error.to_string(), "unexpected character '+' at position 7");
+/// ```
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ParseError {
+    /// The byte offset into the input at which the problem was found.
+    pub position: usize,
+    /// What went wrong.
+    pub kind: ParseErrorKind,
+}
+
+/// The kinds of problem that can be found while parsing.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub enum ParseErrorKind {
+    /// The input contains no terms.
+    Empty,
+    /// A character which cannot appear at this point.
+    UnexpectedCharacter(char),
+    /// The input ended in the middle of a term.
+    UnexpectedEnd,
+    /// A number which could not be read as an `f64`.
+    InvalidNumber(String),
+    /// A fractional exponent such as `x^(1/0)` divides by zero.
+    DivisionByZero,
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.kind {
+            ParseErrorKind::Empty => write!(f, "expected a term")?,
+            ParseErrorKind::UnexpectedCharacter(c) => write!(f, "unexpected character '{c}'")?,
+            ParseErrorKind::UnexpectedEnd => write!(f, "unexpected end of input")?,
+            ParseErrorKind::InvalidNumber(number) => write!(f, "invalid number \"{number}\"")?,
+            ParseErrorKind::DivisionByZero => write!(f, "division by zero")?,
+        }
+        write!(f, " at position {}", self.position)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// A recursive descent parser over the input string.
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, position: 0 }
+    }
+
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError {
+            position: self.position,
+            kind,
+        }
+    }
+
+    /// An error for whatever is at the current position.
+    fn unexpected(&self) -> ParseError {
+        match self.peek() {
+            Some(c) => self.error(ParseErrorKind::UnexpectedCharacter(c)),
+            None => self.error(ParseErrorKind::UnexpectedEnd),
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.position..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek().filter(|c| c.is_whitespace()) {
+            self.position += c.len_utf8();
+        }
+    }
+
+    /// Consumes `expected` (after any whitespace) if it comes next.
+    fn eat(&mut self, expected: &str) -> bool {
+        self.skip_whitespace();
+        if self.input[self.position..].starts_with(expected) {
+            self.position += expected.len();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Consumes a `+` or `-`, returning the sign it stands for.
+    fn sign(&mut self) -> Option<f64> {
+        if self.eat("+") {
+            Some(1_f64)
+        } else if self.eat("-") {
+            Some(-1_f64)
+        } else {
+            None
+        }
+    }
+
+    fn at_end(&mut self) -> bool {
+        self.skip_whitespace();
+        self.position == self.input.len()
+    }
+
+    /// Parses an unsigned decimal number, such as `2`, `2.5`, `.5` or `1e-3`.
+    fn number(&mut self) -> Result<Option<f64>, ParseError> {
+        self.skip_whitespace();
+        let start = self.position;
+        let bytes = self.input.as_bytes();
+        let mut end = start;
+        while end < bytes.len() && (bytes[end].is_ascii_digit() || bytes[end] == b'.') {
+            end += 1;
+        }
+        if end == start {
+            return Ok(None);
+        }
+        // Scientific notation, only if digits follow the `e`.
+        if end < bytes.len() && (bytes[end] == b'e' || bytes[end] == b'E') {
+            let mut exponent_end = end + 1;
+            if exponent_end < bytes.len()
+                && (bytes[exponent_end] == b'+' || bytes[exponent_end] == b'-')
+            {
+                exponent_end += 1;
+            }
+            if exponent_end < bytes.len() && bytes[exponent_end].is_ascii_digit() {
+                end = exponent_end;
+                while end < bytes.len() && bytes[end].is_ascii_digit() {
+                    end += 1;
+                }
+            }
+        }
+        let text = &self.input[start..end];
+        match text.parse::<f64>() {
+            Ok(value) => {
+                self.position = end;
+                Ok(Some(value))
+            }
+            Err(_) => Err(self.error(ParseErrorKind::InvalidNumber(text.to_string()))),
+        }
+    }
+
+    /// Parses a number which must be present, with an optional sign.
+    fn signed_number(&mut self) -> Result<f64, ParseError> {
+        let sign = self.sign().unwrap_or(1_f64);
+        match self.number()? {
+            Some(value) => Ok(sign * value),
+            None => Err(self.unexpected()),
+        }
+    }
+
+    /// Parses the exponent after `^` or `**`: a signed number, or a signed number or fraction in parentheses.
+    fn exponent(&mut self) -> Result<f64, ParseError> {
+        if !self.eat("(") {
+            return self.signed_number();
+        }
+        let numerator = self.signed_number()?;
+        let mut exponent = numerator;
+        if self.eat("/") {
+            self.skip_whitespace();
+            let position = self.position;
+            let denominator = self.signed_number()?;
+            if denominator == 0_f64 {
+                return Err(ParseError {
+                    position,
+                    kind: ParseErrorKind::DivisionByZero,
+                });
+            }
+            exponent = numerator / denominator;
+        }
+        if !self.eat(")") {
+            return Err(self.unexpected());
+        }
+        Ok(exponent)
+    }
+
+    /// Parses a single term, such as `3x^2`, with the given sign.
+    fn term(&mut self, sign: f64) -> Result<Monomial, ParseError> {
+        let coefficient = self.number()?;
+        let has_variable = if coefficient.is_some() && self.eat("*") {
+            if self.eat("x") {
+                true
+            } else {
+                return Err(self.unexpected());
+            }
+        } else {
+            self.eat("x")
+        };
+
+        let e = if !has_variable {
+            0_f64
+        } else if self.eat("**") || self.eat("^") {
+            self.exponent()?
+        } else {
+            1_f64
+        };
+
+        match (coefficient, has_variable) {
+            (None, false) => Err(self.unexpected()),
+            (c, _) => Ok(Monomial::new(sign * c.unwrap_or(1_f64), e)),
+        }
+    }
+
+    /// Parses a term with an optional leading sign, as at the start of the input.
+    fn first_term(&mut self) -> Result<Monomial, ParseError> {
+        if self.at_end() {
+            return Err(self.error(ParseErrorKind::Empty));
+        }
+        let sign = self.sign().unwrap_or(1_f64);
+        self.term(sign)
+    }
+}
+
+/// Parses a single monomial, such as `3x^2`, `-x^-1`, `x^(1/2)` or `4*x**3`.
+///
+/// See the `parse` module for the accepted syntax.
+///
+/// #### Example
+/// ```rust
+/// use calcucalc::Monomial;
+///
+/// assert_eq!("3x^2".parse::<Monomial>().unwrap(), Monomial { c: 3.0, e: 2.0 });
+/// assert_eq!("-x^-1".parse::<Monomial>().unwrap(), Monomial { c: -1.0, e: -1.0 });
+/// assert_eq!("x^(1/2)".parse::<Monomial>().unwrap(), Monomial { c: 1.0, e: 0.5 });
+/// assert_eq!("4*x**3".parse::<Monomial>().unwrap(), Monomial { c: 4.0, e: 3.0 });
+/// assert!("3x + 1".parse::<Monomial>().is_err());
+/// ```
+impl std::str::FromStr for Monomial {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(s);
+        let monomial = parser.first_term()?;
+        if !parser.at_end() {
+            return Err(parser.unexpected());
+        }
+        Ok(monomial)
+    }
+}
+
+/// Parses a polynomial, such as `3x^2 - 2.5x + 1`.
+///
+/// See the `parse` module for the accepted syntax. The monomials are kept in the order they are written in, without being simplified.
+///
+/// #### Example
+/// ```rust
+/// use calcucalc::{Monomial, Polynomial};
+///
+/// let my_polynomial: Polynomial = "3x^2 - 2.5x + 1".parse().unwrap();
+/// assert_eq!(my_polynomial, Polynomial(vec![
+///     Monomial { c: 3.0, e: 2.0 },
+///     Monomial { c: -2.5, e: 1.0 },
+///     Monomial { c: 1.0, e: 0.0 },
+/// ]));
+///
+/// // Parsing is the inverse of formatting.
+/// assert_eq!(my_polynomial.to_string().parse::<Polynomial>().unwrap(), my_polynomial);
+/// ```
+impl std::str::FromStr for Polynomial {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser::new(s);
+        let mut elements = vec![parser.first_term()?];
+        while !parser.at_end() {
+            let Some(sign) = parser.sign() else {
+                return Err(parser.unexpected());
+            };
+            elements.push(parser.term(sign)?);
+        }
+        Ok(Self(elements))
+    }
+}
@@ -0,0 +1,616 @@
+//! Exact rational numbers, for working with monomials and polynomials without floating point error.
+
+use std::cmp::Ordering;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    CalcError, DomainError, Monomial, ParseError, ParseErrorKind, Polynomial, Scalar, Tolerance,
+};
+
+/// The message of the panic when the result of an operation does not fit in an `i64` fraction.
+const OVERFLOW: &str = "rational number overflowed i64";
+
+/// An exact fraction `numerator / denominator`.
+///
+/// Fractions are always kept in lowest terms with a positive denominator, so two equal fractions have equal numerators and denominators.
+/// Arithmetic is carried out with `i128` intermediates, and panics if the result in lowest terms does not fit in `i64`, just as integer arithmetic does.
+/// `checked_add()`, `checked_sub()`, `checked_mul()` and `checked_div()` return an error instead, and `checked_value()` evaluates monomials and polynomials with them.
+///
+/// `Rational` implements `Scalar`, so it can be used for the coefficients and exponents of monomials and polynomials. Unlike `f64`, exponents are only alike if they are exactly equal.
+///
+/// #### Example
+/// ```rust
+/// use calcucalc::{Monomial, Polynomial, Rational};
+///
+/// let third = Rational::new(1, 3);
+/// assert_eq!(third + Rational::new(1, 6), Rational::new(1, 2));
+/// assert_eq!(Rational::new(1, 10) + Rational::new(2, 10), Rational::new(3, 10));
+///
+/// // (1/3)x^3 + x^(1/3)
+/// let my_polynomial = Polynomial(vec![
+///     Monomial::new(third, Rational::from(3)),
+///     Monomial::new(Rational::from(1), third),
+/// ]);
+/// let my_derivative = my_polynomial.derivative().unwrap();
+/// assert_eq!(my_derivative.to_string(), "x^2 + (1/3)x^(-2/3)");
+/// let my_antiderivative = my_derivative.antiderivative(Rational::from(0)).unwrap();
+/// assert_eq!(my_antiderivative.polynomial, my_polynomial);
+/// ```
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[must_use]
+pub struct Rational {
+    numerator: i64,
+    denominator: i64,
+}
+
+impl Rational {
+    /// `0`
+    pub const ZERO: Self = Self {
+        numerator: 0,
+        denominator: 1,
+    };
+
+    /// `1`
+    pub const ONE: Self = Self {
+        numerator: 1,
+        denominator: 1,
+    };
+
+    /// Creates a new fraction, reduced to lowest terms.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::Rational;
+    ///
+    /// let r = Rational::new(6, -4);
+    /// assert_eq!(r.numerator(), -3);
+    /// assert_eq!(r.denominator(), 2);
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the denominator is zero, or if the numerator or denominator is `i64::MIN` and cannot be negated.
+    pub fn new(numerator: i64, denominator: i64) -> Self {
+        Self::reduced(i128::from(numerator), i128::from(denominator))
+    }
+
+    /// Reduces a fraction of `i128`s to lowest terms with a positive denominator.
+    fn reduced(numerator: i128, denominator: i128) -> Self {
+        assert!(denominator != 0, "attempt to divide by zero");
+        Self::checked_reduced(numerator, denominator).expect(OVERFLOW)
+    }
+
+    /// Reduces a fraction of `i128`s with a non-zero denominator, or returns `None` if it does not fit in `i64` in lowest terms.
+    fn checked_reduced(numerator: i128, denominator: i128) -> Option<Self> {
+        let divisor =
+            i128::try_from(gcd(numerator.unsigned_abs(), denominator.unsigned_abs())).ok()?;
+        let sign = denominator.signum();
+        Some(Self {
+            numerator: i64::try_from(sign * numerator / divisor).ok()?,
+            denominator: i64::try_from(sign * denominator / divisor).ok()?,
+        })
+    }
+
+    /// Adds two fractions, or returns an error if the sum does not fit in `i64`.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{CalcError, Rational};
+    ///
+    /// assert_eq!(Rational::new(1, 2).checked_add(Rational::new(1, 3)), Ok(Rational::new(5, 6)));
+    /// assert!(Rational::from(i64::MAX).checked_add(Rational::ONE).is_err());
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// `CalcError::Overflow` if the numerator or denominator of the sum does not fit in `i64`.
+    pub fn checked_add(self, other: Self) -> Result<Self, CalcError> {
+        Self::checked_reduced(
+            i128::from(self.numerator) * i128::from(other.denominator)
+                + i128::from(other.numerator) * i128::from(self.denominator),
+            i128::from(self.denominator) * i128::from(other.denominator),
+        )
+        .ok_or(CalcError::Overflow(self.to_f64() + other.to_f64()))
+    }
+
+    /// Subtracts one fraction from another, or returns an error if the difference does not fit in `i64`.
+    ///
+    /// ## Errors
+    ///
+    /// `CalcError::Overflow` if the numerator or denominator of the difference does not fit in `i64`.
+    pub fn checked_sub(self, other: Self) -> Result<Self, CalcError> {
+        Self::checked_reduced(
+            i128::from(self.numerator) * i128::from(other.denominator)
+                - i128::from(other.numerator) * i128::from(self.denominator),
+            i128::from(self.denominator) * i128::from(other.denominator),
+        )
+        .ok_or(CalcError::Overflow(self.to_f64() - other.to_f64()))
+    }
+
+    /// Multiplies two fractions, or returns an error if the product does not fit in `i64`.
+    ///
+    /// ## Errors
+    ///
+    /// `CalcError::Overflow` if the numerator or denominator of the product does not fit in `i64`.
+    pub fn checked_mul(self, other: Self) -> Result<Self, CalcError> {
+        Self::checked_reduced(
+            i128::from(self.numerator) * i128::from(other.numerator),
+            i128::from(self.denominator) * i128::from(other.denominator),
+        )
+        .ok_or(CalcError::Overflow(self.to_f64() * other.to_f64()))
+    }
+
+    /// Divides one fraction by another, or returns an error if the quotient is not a fraction which fits in `i64`.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{CalcError, Rational};
+    ///
+    /// assert_eq!(Rational::new(1, 2).checked_div(Rational::new(3, 4)), Ok(Rational::new(2, 3)));
+    /// assert_eq!(Rational::ONE.checked_div(Rational::ZERO), Err(CalcError::NonFinite(f64::INFINITY)));
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// - `CalcError::NonFinite` if `other` is zero.
+    /// - `CalcError::Overflow` if the numerator or denominator of the quotient does not fit in `i64`.
+    pub fn checked_div(self, other: Self) -> Result<Self, CalcError> {
+        if other == Self::ZERO {
+            return Err(CalcError::NonFinite(self.to_f64() / 0_f64));
+        }
+        Self::checked_reduced(
+            i128::from(self.numerator) * i128::from(other.denominator),
+            i128::from(self.denominator) * i128::from(other.numerator),
+        )
+        .ok_or(CalcError::Overflow(self.to_f64() / other.to_f64()))
+    }
+
+    /// The numerator, in lowest terms. It carries the sign of the fraction.
+    #[must_use]
+    pub fn numerator(&self) -> i64 {
+        self.numerator
+    }
+
+    /// The denominator, in lowest terms. It is always positive.
+    #[must_use]
+    pub fn denominator(&self) -> i64 {
+        self.denominator
+    }
+
+    /// Whether the fraction is a whole number.
+    #[must_use]
+    pub fn is_integer(&self) -> bool {
+        self.denominator == 1
+    }
+
+    /// Raises the fraction to an integer power, exactly.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::Rational;
+    ///
+    /// assert_eq!(Rational::new(2, 3).powi(3), Rational::new(8, 27));
+    /// assert_eq!(Rational::new(2, 3).powi(-2), Rational::new(9, 4));
+    /// ```
+    ///
+    /// ## Panics
+    ///
+    /// Panics if the result overflows, or if zero is raised to a negative power. Use `checked_powi()` to get an error instead.
+    pub fn powi(self, n: i32) -> Self {
+        self.checked_powi(n)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    /// Raises the fraction to an integer power, exactly, or returns an error if the result is not a fraction which fits in `i64`.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{CalcError, DomainError, Rational};
+    ///
+    /// assert_eq!(Rational::new(2, 3).checked_powi(-2), Ok(Rational::new(9, 4)));
+    /// assert_eq!(
+    ///     Rational::ZERO.checked_powi(-1),
+    ///     Err(CalcError::Domain(DomainError::NonRationalPower { base: 0.0, exponent: -1.0 }))
+    /// );
+    /// assert!(Rational::from(10).checked_powi(19).is_err());
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// - `DomainError::NonRationalPower` if zero is raised to a negative power.
+    /// - `CalcError::Overflow` if the numerator or denominator of the result does not fit in `i64`.
+    pub fn checked_powi(self, n: i32) -> Result<Self, CalcError> {
+        if self == Self::ZERO && n < 0 {
+            return Err(DomainError::NonRationalPower {
+                base: 0_f64,
+                exponent: f64::from(n),
+            }
+            .into());
+        }
+        let overflow = |_| CalcError::Overflow(self.to_f64().powi(n));
+        let mut result = Self::ONE;
+        let mut base = self;
+        let mut remaining = n.unsigned_abs();
+        while remaining > 0 {
+            if remaining % 2 == 1 {
+                result = result.checked_mul(base).map_err(overflow)?;
+            }
+            remaining /= 2;
+            if remaining > 0 {
+                base = base.checked_mul(base).map_err(overflow)?;
+            }
+        }
+        Ok(if n < 0 { Self::ONE / result } else { result })
+    }
+
+    /// Raises the fraction to a fractional power, exactly, or returns an error if the result is not a fraction which fits in `i64`.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{CalcError, DomainError, Rational};
+    ///
+    /// assert_eq!(Rational::new(8, 27).checked_pow(&Rational::new(2, 3)), Ok(Rational::new(4, 9)));
+    /// assert_eq!(
+    ///     Rational::from(2).checked_pow(&Rational::new(1, 2)),
+    ///     Err(CalcError::Domain(DomainError::NonRationalPower { base: 2.0, exponent: 0.5 }))
+    /// );
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// - `DomainError::NonRationalPower` if the result is irrational, as for `2^(1/2)`, or if zero is raised to a negative power.
+    /// - `DomainError::FractionalPowerOfNegative` if the result is not real, as for `(-1)^(1/2)`.
+    /// - `CalcError::Overflow` if the numerator or denominator of the result does not fit in `i64`.
+    pub fn checked_pow(self, exponent: &Self) -> Result<Self, CalcError> {
+        let non_rational = || {
+            CalcError::from(DomainError::NonRationalPower {
+                base: self.to_f64(),
+                exponent: exponent.to_f64(),
+            })
+        };
+        if self == Self::ONE || (self == Self::ZERO && exponent.numerator == 0) {
+            return Ok(Self::ONE);
+        }
+        if self == Self::ZERO {
+            return if exponent.numerator > 0 {
+                Ok(Self::ZERO)
+            } else {
+                Err(non_rational())
+            };
+        }
+        if self.numerator < 0 && exponent.denominator % 2 == 0 {
+            return Err(DomainError::FractionalPowerOfNegative {
+                exponent: exponent.to_f64(),
+            }
+            .into());
+        }
+        let Ok(power) = i32::try_from(exponent.numerator) else {
+            return Err(CalcError::Overflow(self.to_f64().powf(exponent.to_f64())));
+        };
+        if exponent.is_integer() {
+            return self.checked_powi(power);
+        }
+        let root = u32::try_from(exponent.denominator)
+            .ok()
+            .and_then(|n| self.exact_root(n))
+            .ok_or_else(non_rational)?;
+        root.checked_powi(power)
+    }
+
+    /// Takes the `n`th root, if it is a fraction.
+    fn exact_root(self, n: u32) -> Option<Self> {
+        if self.numerator < 0 && n.is_multiple_of(2) {
+            return None;
+        }
+        let numerator = integer_root(self.numerator.unsigned_abs(), n)?;
+        let denominator = integer_root(self.denominator.unsigned_abs(), n)?;
+        let numerator = i128::from(numerator) * i128::from(self.numerator.signum());
+        Some(Self::reduced(numerator, i128::from(denominator)))
+    }
+}
+
+/// The greatest common divisor of two integers, with `gcd(0, 0) = 1` so that it can always be divided by.
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    if a == 0 { 1 } else { a }
+}
+
+/// The `n`th root of an integer, if it is an integer.
+fn integer_root(value: u64, n: u32) -> Option<u64> {
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    let estimate = (value as f64).powf(1_f64 / f64::from(n)).round() as u64;
+    // The estimate can be off by one for large values.
+    [estimate.saturating_sub(1), estimate, estimate + 1]
+        .into_iter()
+        .find(|root| root.checked_pow(n) == Some(value))
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(value: i64) -> Self {
+        Self {
+            numerator: value,
+            denominator: 1,
+        }
+    }
+}
+
+impl From<i32> for Rational {
+    fn from(value: i32) -> Self {
+        Self::from(i64::from(value))
+    }
+}
+
+impl From<Rational> for f64 {
+    fn from(value: Rational) -> Self {
+        value.to_f64()
+    }
+}
+
+/// Converts an `f64` into the fraction with exactly the same value.
+///
+/// Every finite `f64` is a fraction with a power of two as its denominator. Values whose denominator would not fit in `i64` are rounded to the nearest multiple of `2^-62`.
+///
+/// #### Example
+/// ```rust
+/// use calcucalc::{CalcError, Rational};
+///
+/// assert_eq!(Rational::try_from(0.75), Ok(Rational::new(3, 4)));
+/// // 0.1 cannot be represented exactly as an f64.
+/// assert_eq!(Rational::try_from(0.1), Ok(Rational::new(3602879701896397, 36028797018963968)));
+/// assert_eq!(Rational::try_from(f64::NAN).is_err(), true);
+/// assert_eq!(Rational::try_from(1e300), Err(CalcError::Overflow(1e300)));
+/// ```
+impl TryFrom<f64> for Rational {
+    type Error = CalcError;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if !value.is_finite() {
+            return Err(CalcError::NonFinite(value));
+        }
+        if value == 0_f64 {
+            return Ok(Self::ZERO);
+        }
+
+        // value = mantissa * 2^exponent
+        let bits = value.to_bits();
+        let biased_exponent = i32::try_from((bits >> 52) & 0x7ff).expect(OVERFLOW);
+        let fraction = bits & ((1 << 52) - 1);
+        let (mut mantissa, mut exponent) = if biased_exponent == 0 {
+            (fraction, -1074)
+        } else {
+            (fraction | (1 << 52), biased_exponent - 1075)
+        };
+
+        let sign = if value < 0_f64 { -1 } else { 1 };
+        if exponent >= 0 {
+            let numerator = i128::from(mantissa) << exponent.unsigned_abs().min(64);
+            if exponent >= 64 || numerator > i128::from(i64::MAX) {
+                return Err(CalcError::Overflow(value));
+            }
+            return Ok(Self::reduced(sign * numerator, 1));
+        }
+
+        let trailing_zeros = mantissa.trailing_zeros().min(exponent.unsigned_abs());
+        mantissa >>= trailing_zeros;
+        exponent += i32::try_from(trailing_zeros).expect(OVERFLOW);
+        if exponent < -62 {
+            let shift = (-62 - exponent).unsigned_abs();
+            mantissa = if shift >= 64 {
+                0
+            } else {
+                // Round to nearest.
+                (mantissa >> shift) + ((mantissa >> (shift - 1)) & 1)
+            };
+            exponent = -62;
+        }
+        Ok(Self::reduced(
+            sign * i128::from(mantissa),
+            1_i128 << exponent.unsigned_abs(),
+        ))
+    }
+}
+
+/// Formats the fraction as, for example, `-1/3`, or as an integer such as `2` if the denominator is `1`.
+impl std::fmt::Display for Rational {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+/// Parses a fraction such as `1/3`, `-5/7` or `2`.
+///
+/// #### Example
+/// ```rust
+/// use calcucalc::Rational;
+///
+/// assert_eq!("2/-6".parse::<Rational>().unwrap(), Rational::new(-1, 3));
+/// assert_eq!("7".parse::<Rational>().unwrap(), Rational::from(7));
+/// assert!("1/0".parse::<Rational>().is_err());
+/// ```
+impl std::str::FromStr for Rational {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |position: usize, text: &str| ParseError {
+            position,
+            kind: ParseErrorKind::InvalidNumber(text.trim().to_string()),
+        };
+        let (numerator, denominator, denominator_position) = match s.split_once('/') {
+            Some((numerator, denominator)) => (numerator, denominator, numerator.len() + 1),
+            None => (s, "1", s.len()),
+        };
+        let numerator: i64 = numerator
+            .trim()
+            .parse()
+            .map_err(|_| invalid(0, numerator))?;
+        let denominator: i64 = denominator
+            .trim()
+            .parse()
+            .map_err(|_| invalid(denominator_position, denominator))?;
+        if denominator == 0 {
+            return Err(ParseError {
+                position: denominator_position,
+                kind: ParseErrorKind::DivisionByZero,
+            });
+        }
+        Ok(Self::new(numerator, denominator))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // The denominators are positive, so cross-multiplying keeps the order.
+        let left = i128::from(self.numerator) * i128::from(other.denominator);
+        let right = i128::from(other.numerator) * i128::from(self.denominator);
+        left.cmp(&right)
+    }
+}
+
+impl Add for Rational {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.checked_add(other).expect(OVERFLOW)
+    }
+}
+
+impl Sub for Rational {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.checked_sub(other).expect(OVERFLOW)
+    }
+}
+
+impl Mul for Rational {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        self.checked_mul(other).expect(OVERFLOW)
+    }
+}
+
+impl Div for Rational {
+    type Output = Self;
+
+    fn div(self, other: Self) -> Self {
+        Self::reduced(
+            i128::from(self.numerator) * i128::from(other.denominator),
+            i128::from(self.denominator) * i128::from(other.numerator),
+        )
+    }
+}
+
+impl Neg for Rational {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::reduced(-i128::from(self.numerator), i128::from(self.denominator))
+    }
+}
+
+/// Fractions are always compared exactly, whatever the tolerance, so exponents are only alike if they are exactly equal.
+///
+/// `pow()` is always exact, as for `(8/27)^(2/3) = 4/9`.
+///
+/// ## Panics
+///
+/// `pow()` panics if the result is not a fraction which fits in `i64`, as for `2^(1/2)`, `(-1)^(1/2)` or `0^-1`.
+/// This includes evaluating a `Monomial<Rational>` or `Polynomial<Rational>` with `value()`, so use `checked_value()` to get an error instead.
+impl Scalar for Rational {
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    fn one() -> Self {
+        Self::ONE
+    }
+
+    fn pow(self, exponent: &Self) -> Self {
+        self.checked_pow(exponent)
+            .unwrap_or_else(|error| panic!("{error}"))
+    }
+
+    fn is_equal_within(&self, other: &Self, _tolerance: Tolerance) -> bool {
+        self == other
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    fn to_f64(&self) -> f64 {
+        self.numerator as f64 / self.denominator as f64
+    }
+}
+
+impl Monomial<Rational> {
+    /// Calculates the value of the monomial for a given value of x exactly, or returns an error if it is not a fraction which fits in `i64`.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{CalcError, DomainError, Monomial, Rational};
+    ///
+    /// let m = Monomial::new(Rational::from(3), Rational::new(2, 3));
+    /// assert_eq!(m.checked_value(Rational::new(8, 27)), Ok(Rational::new(4, 3)));
+    /// assert_eq!(
+    ///     m.checked_value(Rational::from(2)),
+    ///     Err(CalcError::Domain(DomainError::NonRationalPower { base: 2.0, exponent: 2.0 / 3.0 }))
+    /// );
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// The errors of `Rational::checked_pow()` and `Rational::checked_mul()`.
+    pub fn checked_value(&self, x: Rational) -> Result<Rational, CalcError> {
+        self.c.checked_mul(x.checked_pow(&self.e)?)
+    }
+}
+
+impl Polynomial<Rational> {
+    /// Calculates the value of the polynomial for a given value of x exactly, or returns an error if it is not a fraction which fits in `i64`.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{CalcError, DomainError, Monomial, Polynomial, Rational};
+    ///
+    /// // x^(1/3) + 1
+    /// let my_polynomial = Polynomial(vec![
+    ///     Monomial::new(Rational::ONE, Rational::new(1, 3)),
+    ///     Monomial::new(Rational::ONE, Rational::ZERO),
+    /// ]);
+    /// assert_eq!(my_polynomial.checked_value(Rational::new(-8, 27)), Ok(Rational::new(1, 3)));
+    /// assert!(my_polynomial.checked_value(Rational::from(2)).is_err());
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// - `DomainError::NonRationalPower` or `DomainError::FractionalPowerOfNegative` if a power of x is not a fraction, as in `Rational::checked_pow()`.
+    /// - `CalcError::Overflow` if a term or the sum does not fit in `i64`.
+    pub fn checked_value(&self, x: Rational) -> Result<Rational, CalcError> {
+        self.0.iter().try_fold(Rational::ZERO, |value, element| {
+            value.checked_add(element.checked_value(x)?)
+        })
+    }
+}
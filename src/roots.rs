@@ -0,0 +1,403 @@
+//! Root finding for polynomials with integer exponents.
+
+use serde::{Deserialize, Serialize};
+
+use crate::{CalcError, Complex, DomainError, Polynomial, coefficients};
+
+/// A real root of a polynomial, together with how many times it is repeated.
+///
+/// For example, `x^2 - 2x + 1 = (x - 1)^2` has the root `1` with a multiplicity of `2`.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, PartialOrd, Serialize)]
+pub struct RealRoot {
+    /// The x-value of the root.
+    pub x: f64,
+    /// The number of times the root is repeated.
+    pub multiplicity: u32,
+}
+
+/// A complex root of a polynomial, together with bounds on how accurate it is.
+///
+/// Repeated roots are listed once per repetition.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, PartialOrd, Serialize)]
+pub struct ComplexRoot {
+    /// The location of the root in the complex plane.
+    pub z: Complex,
+    /// The absolute value of the polynomial at `z`.
+    pub residual: f64,
+    /// A true root lies within this distance of `z`.
+    ///
+    /// This is the radius `n * |p(z) / p'(z)|` of the Newton inclusion disk, where `n` is the degree of the polynomial, widened to allow for rounding error in `p(z)`.
+    /// It is large for repeated roots, where `p'(z)` is close to zero, and infinite when `p'(z)` is exactly zero.
+    pub error_bound: f64,
+}
+
+impl Polynomial {
+    /// Finds every real root of a polynomial with integer exponents.
+    ///
+    /// The distinct roots are isolated with a Sturm sequence, and each one is then refined with a combination of bisection and Newton's method until it is known to within `tolerance`.
+    /// Repeated roots are detected with the greatest common divisor of the polynomial and its derivative, and reported once with their multiplicity.
+    ///
+    /// The roots are returned in ascending order.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{Monomial, Polynomial, RealRoot};
+    ///
+    /// // (x - 1)^2 (x + 2) = x^3 - 3x + 2
+    /// let my_polynomial = Polynomial(vec![
+    ///     Monomial { c: 1.0, e: 3.0 },
+    ///     Monomial { c: -3.0, e: 1.0 },
+    ///     Monomial { c: 2.0, e: 0.0 },
+    /// ]);
+    /// let roots = my_polynomial.real_roots(1e-12).unwrap();
+    /// assert_eq!(roots.len(), 2);
+    /// assert!((roots[0].x + 2.0).abs() < 1e-9);
+    /// assert_eq!(roots[0].multiplicity, 1);
+    /// assert!((roots[1].x - 1.0).abs() < 1e-9);
+    /// assert_eq!(roots[1].multiplicity, 2);
+    /// ```
+    ///
+    /// Negative integer exponents are allowed. `x = 0` is never reported as a root of a polynomial with a negative exponent, because the polynomial is undefined there.
+    /// ```rust
+    /// use calcucalc::{Monomial, Polynomial};
+    ///
+    /// // x - 4/x
+    /// let my_polynomial = Polynomial(vec![
+    ///     Monomial { c: 1.0, e: 1.0 },
+    ///     Monomial { c: -4.0, e: -1.0 },
+    /// ]);
+    /// let roots = my_polynomial.real_roots(1e-12).unwrap();
+    /// assert_eq!(roots.len(), 2);
+    /// assert!((roots[0].x + 2.0).abs() < 1e-9);
+    /// assert!((roots[1].x - 2.0).abs() < 1e-9);
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// An error is returned if:
+    /// - any exponent of x is not an integer,
+    /// - the exponents of x span too many powers to store a coefficient for each of them,
+    /// - the polynomial is zero (and so has infinitely many roots), or
+    /// - `tolerance` is not a positive number.
+    pub fn real_roots(&self, tolerance: f64) -> Result<Vec<RealRoot>, CalcError> {
+        if tolerance.is_nan() || tolerance <= 0_f64 {
+            return Err(DomainError::InvalidTolerance(tolerance).into());
+        }
+
+        let (mut coefficients, shift) = coefficients::from_polynomial(self)?;
+        if coefficients.is_empty() {
+            return Err(DomainError::InfinitelyManyRoots.into());
+        }
+
+        // Factor out x^k, so that the remaining polynomial has no root at 0.
+        let zeros = coefficients.iter().take_while(|c| **c == 0_f64).count();
+        coefficients.drain(..zeros);
+        let zero_multiplicity = shift.saturating_add(i32::try_from(zeros).unwrap_or(i32::MAX));
+
+        let mut roots = real_roots_of_coefficients(&coefficients, tolerance);
+        if zero_multiplicity > 0 {
+            roots.push(RealRoot {
+                x: 0_f64,
+                multiplicity: zero_multiplicity.unsigned_abs(),
+            });
+        }
+        roots.sort_by(|a, b| a.x.total_cmp(&b.x));
+        Ok(roots)
+    }
+
+    /// Finds every complex root of a polynomial with integer exponents.
+    ///
+    /// All of the roots are found at once with the Aberth–Ehrlich method. A polynomial of degree `n` has exactly `n` complex roots, counting repeated roots once per repetition.
+    /// Each root comes with its residual and an error bound, see `ComplexRoot`.
+    ///
+    /// The roots are sorted by their real part, and then by their imaginary part.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::{Complex, Monomial, Polynomial};
+    ///
+    /// // x^2 + 2x + 5 has the roots -1 ± 2i.
+    /// let my_polynomial = Polynomial(vec![
+    ///     Monomial { c: 1.0, e: 2.0 },
+    ///     Monomial { c: 2.0, e: 1.0 },
+    ///     Monomial { c: 5.0, e: 0.0 },
+    /// ]);
+    /// let roots = my_polynomial.complex_roots().unwrap();
+    /// assert_eq!(roots.len(), 2);
+    /// assert!((roots[0].z - Complex::new(-1.0, -2.0)).norm() < 1e-12);
+    /// assert!((roots[1].z - Complex::new(-1.0, 2.0)).norm() < 1e-12);
+    /// assert!(roots.iter().all(|root| root.error_bound < 1e-12));
+    /// ```
+    ///
+    /// As with `real_roots()`, negative integer exponents are allowed, and `x = 0` is never a root of a polynomial with a negative exponent.
+    ///
+    /// ## Errors
+    ///
+    /// An error is returned if any exponent of x is not an integer, if the exponents of x span too many powers to store a coefficient for each of them,
+    /// or if the polynomial is zero.
+    pub fn complex_roots(&self) -> Result<Vec<ComplexRoot>, CalcError> {
+        let (mut coefficients, shift) = coefficients::from_polynomial(self)?;
+        if coefficients.is_empty() {
+            return Err(DomainError::InfinitelyManyRoots.into());
+        }
+
+        // Factor out x^k, so that the remaining polynomial has no root at 0.
+        let zeros = coefficients.iter().take_while(|c| **c == 0_f64).count();
+        coefficients.drain(..zeros);
+        let zero_multiplicity = shift.saturating_add(i32::try_from(zeros).unwrap_or(i32::MAX));
+
+        let derivative = coefficients::derivative(&coefficients);
+        let absolute: Vec<f64> = coefficients.iter().map(|c| c.abs()).collect();
+        let degree = coefficients.len() - 1;
+        #[allow(clippy::cast_precision_loss)]
+        let n = degree as f64;
+
+        let mut roots: Vec<ComplexRoot> = aberth(&coefficients)
+            .into_iter()
+            .map(|mut z| {
+                // Parts at the level of rounding error are noise, and only
+                // get in the way of telling real roots from complex ones.
+                let noise = f64::EPSILON * (1_f64 + z.norm());
+                if z.re.abs() <= noise {
+                    z.re = 0_f64;
+                }
+                if z.im.abs() <= noise {
+                    z.im = 0_f64;
+                }
+                let value = evaluate_complex(&coefficients, z);
+                let slope = evaluate_complex(&derivative, z);
+                let residual = value.norm();
+                // A residual of exactly zero is usually luck, so allow for the
+                // rounding error made while evaluating the polynomial.
+                let rounding =
+                    2_f64 * n * f64::EPSILON * coefficients::evaluate(&absolute, z.norm());
+                let error_bound = n * (residual + rounding) / slope.norm();
+                ComplexRoot {
+                    z,
+                    residual,
+                    error_bound,
+                }
+            })
+            .collect();
+        for _ in 0..zero_multiplicity.max(0) {
+            roots.push(ComplexRoot::default());
+        }
+        roots.sort_by(|a, b| a.z.re.total_cmp(&b.z.re).then(a.z.im.total_cmp(&b.z.im)));
+        Ok(roots)
+    }
+}
+
+/// Evaluates a polynomial with real coefficients at a complex z using Horner's method.
+fn evaluate_complex(coefficients: &[f64], z: Complex) -> Complex {
+    coefficients
+        .iter()
+        .rev()
+        .fold(Complex::default(), |value, c| value * z + Complex::from(*c))
+}
+
+/// Finds all roots of a polynomial with a non-zero constant term, using the Aberth–Ehrlich method.
+fn aberth(p: &[f64]) -> Vec<Complex> {
+    const MAX_ITERATIONS: usize = 500;
+
+    let degree = p.len() - 1;
+    if degree == 0 {
+        return vec![];
+    }
+    let dp = coefficients::derivative(p);
+
+    // Start on a circle whose radius is the geometric mean of the roots' sizes,
+    // rotated a little so that no guess starts on the real axis.
+    #[allow(clippy::cast_precision_loss)]
+    let n = degree as f64;
+    let radius = (p[0] / p[degree]).abs().powf(1_f64 / n);
+    let mut roots: Vec<Complex> = (0..degree)
+        .map(|k| {
+            #[allow(clippy::cast_precision_loss)]
+            let angle = 2_f64 * std::f64::consts::PI * (k as f64) / n + 0.4;
+            Complex::from_polar(radius, angle)
+        })
+        .collect();
+
+    for _ in 0..MAX_ITERATIONS {
+        let mut converged = true;
+        for k in 0..degree {
+            let z = roots[k];
+            let value = evaluate_complex(p, z);
+            if value.norm() == 0_f64 {
+                continue;
+            }
+            let newton = value / evaluate_complex(&dp, z);
+            let repulsion = roots
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != k)
+                .fold(Complex::default(), |sum, (_, other)| {
+                    sum + Complex::from(1_f64) / (z - *other)
+                });
+            let correction = newton / (Complex::from(1_f64) - newton * repulsion);
+            if !correction.re.is_finite() || !correction.im.is_finite() {
+                continue;
+            }
+            roots[k] = z - correction;
+            if correction.norm() > f64::EPSILON * (1_f64 + z.norm()) {
+                converged = false;
+            }
+        }
+        if converged {
+            break;
+        }
+    }
+    roots
+}
+
+/// Finds the real roots of a non-zero polynomial given as coefficients.
+fn real_roots_of_coefficients(p: &[f64], tolerance: f64) -> Vec<RealRoot> {
+    if coefficients::degree(p).unwrap_or(0) == 0 {
+        return vec![];
+    }
+
+    // Every root of the square-free part q = p / gcd(p, p') is simple, and
+    // the roots of gcd(p, p') are exactly the repeated roots of p, each with
+    // one less multiplicity.
+    let repeated = coefficients::gcd(p, &coefficients::derivative(p));
+    let (square_free, _) = coefficients::div_rem(p, &repeated);
+    let repeated_roots = real_roots_of_coefficients(&repeated, tolerance);
+
+    let sturm = sturm_sequence(&square_free);
+    let bound = cauchy_bound(&square_free);
+    let total = sign_changes(&sturm, -bound).saturating_sub(sign_changes(&sturm, bound));
+
+    let mut intervals = vec![];
+    isolate(&sturm, -bound, bound, total, tolerance, &mut intervals);
+
+    let square_free_derivative = coefficients::derivative(&square_free);
+    intervals
+        .into_iter()
+        .map(|(start, end, count)| {
+            let x = if count == 1 {
+                refine(&square_free, &square_free_derivative, start, end, tolerance)
+            } else {
+                // Roots closer together than the tolerance cannot be told apart.
+                0.5 * (start + end)
+            };
+            let matching = repeated_roots
+                .iter()
+                .filter(|root| (root.x - x).abs() <= tolerance.max(1e-6 * (1_f64 + x.abs())))
+                .map(|root| root.multiplicity)
+                .max()
+                .unwrap_or(0);
+            RealRoot {
+                x,
+                multiplicity: count + matching,
+            }
+        })
+        .collect()
+}
+
+/// Builds the Sturm sequence `p, p', -rem(p, p'), ...` of a polynomial.
+fn sturm_sequence(p: &[f64]) -> Vec<Vec<f64>> {
+    let mut sequence = vec![p.to_vec(), coefficients::derivative(p)];
+    loop {
+        let last = &sequence[sequence.len() - 1];
+        if coefficients::degree(last).unwrap_or(0) == 0 {
+            break;
+        }
+        let (_, remainder) = coefficients::div_rem(&sequence[sequence.len() - 2], last);
+        if coefficients::degree(&remainder).is_none() {
+            break;
+        }
+        // Only the signs matter, so keep the coefficients at a sensible size.
+        let scale = coefficients::scale(&remainder);
+        sequence.push(remainder.iter().map(|c| -c / scale).collect());
+    }
+    sequence
+}
+
+/// Counts the sign changes of the Sturm sequence at x, ignoring zeros.
+fn sign_changes(sturm: &[Vec<f64>], x: f64) -> u32 {
+    let mut changes = 0;
+    let mut previous = 0_f64;
+    for p in sturm {
+        let value = coefficients::evaluate(p, x);
+        if value == 0_f64 {
+            continue;
+        }
+        if previous != 0_f64 && (value < 0_f64) != (previous < 0_f64) {
+            changes += 1;
+        }
+        previous = value;
+    }
+    changes
+}
+
+/// Every root lies strictly within this distance of 0.
+fn cauchy_bound(p: &[f64]) -> f64 {
+    let leading = p[p.len() - 1];
+    1_f64
+        + p[..p.len() - 1]
+            .iter()
+            .fold(0_f64, |max, c| max.max((c / leading).abs()))
+}
+
+/// Splits `(start, end]` by bisection until every piece contains a single root, or is too narrow to split any further.
+fn isolate(
+    sturm: &[Vec<f64>],
+    start: f64,
+    end: f64,
+    count: u32,
+    tolerance: f64,
+    intervals: &mut Vec<(f64, f64, u32)>,
+) {
+    if count == 0 {
+        return;
+    }
+    if count == 1 || end - start <= tolerance {
+        intervals.push((start, end, count));
+        return;
+    }
+    let middle = 0.5 * (start + end);
+    if middle <= start || middle >= end {
+        // The interval cannot be split any further in floating point arithmetic.
+        intervals.push((start, end, count));
+        return;
+    }
+    let left = sign_changes(sturm, start)
+        .saturating_sub(sign_changes(sturm, middle))
+        .min(count);
+    isolate(sturm, start, middle, left, tolerance, intervals);
+    isolate(sturm, middle, end, count - left, tolerance, intervals);
+}
+
+/// Refines the single simple root in `(start, end]` with safeguarded Newton steps.
+fn refine(p: &[f64], dp: &[f64], mut start: f64, mut end: f64, tolerance: f64) -> f64 {
+    if coefficients::evaluate(p, end) == 0_f64 {
+        return end;
+    }
+    let start_is_negative = coefficients::evaluate(p, start) < 0_f64;
+    let mut x = 0.5 * (start + end);
+    while end - start > tolerance {
+        let value = coefficients::evaluate(p, x);
+        if value == 0_f64 {
+            return x;
+        }
+        if (value < 0_f64) == start_is_negative {
+            start = x;
+        } else {
+            end = x;
+        }
+
+        // Take the Newton step if it stays inside the bracket, and bisect otherwise.
+        let slope = coefficients::evaluate(dp, x);
+        let newton = x - value / slope;
+        let next = if newton > start && newton < end {
+            newton
+        } else {
+            0.5 * (start + end)
+        };
+        if (next - x).abs() <= 0.5 * tolerance {
+            return next;
+        }
+        x = next;
+    }
+    0.5 * (start + end)
+}
@@ -0,0 +1,164 @@
+//! The `Scalar` trait, for the numbers used as coefficients and exponents of monomials and polynomials.
+//!
+//! `Monomial` and `Polynomial` use `f64` unless told otherwise, but the algebraic operations (simplifying, adding, multiplying, differentiating and integrating) work with any `Scalar`.
+//! This library implements `Scalar` for `f64`, `f32`, `Complex` and `Rational`.
+//!
+//! #### Example
+//! ```rust
+//! use calcucalc::{Monomial, Polynomial};
+//!
+//! let my_polynomial: Polynomial<f32> = Polynomial(vec![
+//!     Monomial { c: 3.0_f32, e: 2.0 },
+//!     Monomial { c: 2.0, e: 1.0 },
+//! ]);
+//! assert_eq!(my_polynomial.value(2.0), 16.0_f32);
+//! assert_eq!(my_polynomial.derivative().unwrap(), Polynomial(vec![
+//!     Monomial { c: 6.0_f32, e: 1.0 },
+//!     Monomial { c: 2.0, e: 0.0 },
+//! ]));
+//! ```
+
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::{Complex, Polynomial, Tolerance, multiplication};
+
+/// A number which can be used as the coefficient and exponent of a monomial.
+pub trait Scalar:
+    Clone
+    + Debug
+    + PartialEq
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// The additive identity, `0`.
+    fn zero() -> Self;
+
+    /// The multiplicative identity, `1`.
+    fn one() -> Self;
+
+    /// Raises `self` to the power of `exponent`.
+    #[must_use]
+    fn pow(self, exponent: &Self) -> Self;
+
+    /// The tolerance used when none is given, which is the crate-wide default unless this type of number needs its own.
+    #[must_use]
+    fn default_tolerance() -> Tolerance {
+        Tolerance::global()
+    }
+
+    /// Checks if two values are equal within the given tolerance.
+    fn is_equal_within(&self, other: &Self, tolerance: Tolerance) -> bool;
+
+    /// Checks if two values are equal within the default tolerance of this type of number.
+    ///
+    /// This is how monomials decide whether their exponents are alike.
+    fn is_equal_within_tolerance_to(&self, other: &Self) -> bool {
+        self.is_equal_within(other, Self::default_tolerance())
+    }
+
+    /// An approximation of the value as an `f64`, used when reporting errors.
+    fn to_f64(&self) -> f64;
+
+    /// Multiplies two polynomials with a faster algorithm than multiplying every pair of monomials, if this type of number has one which suits them.
+    ///
+    /// `Polynomial::multiply_polynomial()` multiplies every pair of monomials when this returns `None`, as it does by default.
+    /// For `f64`, polynomials of at least 32 monomials with integer exponents are multiplied as coefficient vectors with Karatsuba's algorithm, or with the fast Fourier transform if they have at least 4096.
+    #[must_use]
+    fn fast_product(_a: &Polynomial<Self>, _b: &Polynomial<Self>) -> Option<Polynomial<Self>> {
+        None
+    }
+}
+
+/// Uses the crate-wide default tolerance.
+impl Scalar for f64 {
+    fn zero() -> Self {
+        0_f64
+    }
+
+    fn one() -> Self {
+        1_f64
+    }
+
+    fn pow(self, exponent: &Self) -> Self {
+        self.powf(*exponent)
+    }
+
+    fn is_equal_within(&self, other: &Self, tolerance: Tolerance) -> bool {
+        tolerance.is_equal(*self, *other)
+    }
+
+    fn to_f64(&self) -> f64 {
+        *self
+    }
+
+    fn fast_product(a: &Polynomial, b: &Polynomial) -> Option<Polynomial> {
+        multiplication::multiply_polynomials(a, b)
+    }
+}
+
+/// Uses a default tolerance of `1e-5`, as `f32` only has about 7 significant digits.
+///
+/// A `Tolerance::Ulps` counts representable `f32` values.
+impl Scalar for f32 {
+    fn zero() -> Self {
+        0_f32
+    }
+
+    fn one() -> Self {
+        1_f32
+    }
+
+    fn pow(self, exponent: &Self) -> Self {
+        self.powf(*exponent)
+    }
+
+    fn default_tolerance() -> Tolerance {
+        Tolerance::Absolute(1e-5)
+    }
+
+    fn is_equal_within(&self, other: &Self, tolerance: Tolerance) -> bool {
+        tolerance.is_equal_f32(*self, *other)
+    }
+
+    fn to_f64(&self) -> f64 {
+        f64::from(*self)
+    }
+}
+
+/// Complex numbers are equal within an absolute or relative tolerance if the distance between them is within it, relative to the larger of their norms.
+/// Under a `Tolerance::Ulps`, their real and imaginary parts are compared separately.
+///
+/// `to_f64()` gives the real part.
+impl Scalar for Complex {
+    fn zero() -> Self {
+        Self::new(0_f64, 0_f64)
+    }
+
+    fn one() -> Self {
+        Self::new(1_f64, 0_f64)
+    }
+
+    fn pow(self, exponent: &Self) -> Self {
+        self.powc(*exponent)
+    }
+
+    fn is_equal_within(&self, other: &Self, tolerance: Tolerance) -> bool {
+        let distance = (*self - *other).norm();
+        match tolerance {
+            Tolerance::Absolute(amount) => distance <= amount,
+            Tolerance::Relative(amount) => distance <= amount * self.norm().max(other.norm()),
+            Tolerance::Ulps(_) => {
+                tolerance.is_equal(self.re, other.re) && tolerance.is_equal(self.im, other.im)
+            }
+        }
+    }
+
+    fn to_f64(&self) -> f64 {
+        self.re
+    }
+}
@@ -0,0 +1,491 @@
+//! The algebraic simplifier behind `Expr::simplify()`.
+//!
+//! Every expression is expanded into a sum of terms, each of which is a coefficient times a product of powers of other expressions, like `6 * x * cos(x)^2`.
+//! The terms which are monomials in x are combined with `Polynomial::simplified()`, and the others are sorted and combined with each other,
+//! so that expressions which only differ by the rules applied here end up as the same tree.
+
+use std::cmp::Ordering;
+use std::ops::Add;
+
+use crate::{Expr, Monomial, Polynomial};
+
+/// The highest power of a sum which is multiplied out.
+const MAX_EXPANDED_POWER: u32 = 16;
+
+/// A coefficient times a product of powers, whose bases are sorted.
+///
+/// The bases are distinct, except that a base is repeated when merging its powers would enlarge the domain, as in `x * x^(-1)`.
+#[derive(Clone)]
+struct Term {
+    coefficient: f64,
+    factors: Vec<(Expr, Expr)>,
+}
+
+/// Simplifies an expression into canonical form, see `Expr::simplify()`.
+pub(crate) fn simplify(expression: &Expr) -> Expr {
+    let simplified = match expression {
+        Expr::Constant(_) | Expr::X => expression.clone(),
+        Expr::Add(a, b) => sum(expand(&(simplify(a) + simplify(b)))),
+        Expr::Sub(a, b) => sum(expand(&(simplify(a) - simplify(b)))),
+        Expr::Mul(a, b) => sum(expand(&(simplify(a) * simplify(b)))),
+        Expr::Div(a, b) => sum(expand(&(simplify(a) / simplify(b)))),
+        Expr::Pow(base, exponent) => sum(expand(&simplify(base).pow(simplify(exponent)))),
+        Expr::Sin(a) => simplify(a).sin(),
+        Expr::Cos(a) => simplify(a).cos(),
+        Expr::Tan(a) => simplify(a).tan(),
+        Expr::Exp(a) => simplify(a).exp(),
+        Expr::Ln(a) => match simplify(a) {
+            Expr::Exp(b) => *b,
+            b => b.ln(),
+        },
+    };
+    if simplified.is_constant() {
+        let value = simplified.value(0_f64);
+        if value.is_finite() {
+            return Expr::Constant(value);
+        }
+    }
+    simplified
+}
+
+/// Expands an expression whose subexpressions are simplified into a sum of terms, with like terms combined.
+fn expand(expression: &Expr) -> Vec<Term> {
+    let mut terms = vec![];
+    push_terms(expression, false, &mut terms);
+    combine(terms)
+}
+
+/// Adds the terms of a sum or difference to `terms`, negating them if `negate` is set.
+fn push_terms(expression: &Expr, negate: bool, terms: &mut Vec<Term>) {
+    match expression {
+        Expr::Add(a, b) => {
+            push_terms(a, negate, terms);
+            push_terms(b, negate, terms);
+        }
+        Expr::Sub(a, b) => {
+            push_terms(a, negate, terms);
+            push_terms(b, !negate, terms);
+        }
+        _ => terms.extend(product_terms(expression).into_iter().map(|mut term| {
+            if negate {
+                term.coefficient = -term.coefficient;
+            }
+            term
+        })),
+    }
+}
+
+/// Turns a product into terms, multiplying out the sums in it which are raised to a small positive integer power.
+fn product_terms(expression: &Expr) -> Vec<Term> {
+    let mut term = Term {
+        coefficient: 1_f64,
+        factors: vec![],
+    };
+    push_factors(expression, 1_f64, &mut term);
+    let term = term.normalized();
+
+    let mut sums = vec![];
+    let mut factors = vec![];
+    for (base, exponent) in term.factors {
+        match expanded_power(&base, &exponent) {
+            Some(power) => sums.push((base, power)),
+            None => factors.push((base, exponent)),
+        }
+    }
+
+    let mut terms = vec![Term {
+        coefficient: term.coefficient,
+        factors,
+    }];
+    for (base, power) in sums {
+        let base_terms = expand(&base);
+        for _ in 0..power {
+            terms = combine(
+                terms
+                    .iter()
+                    .flat_map(|a| base_terms.iter().map(|b| a.multiply(b)))
+                    .collect(),
+            );
+        }
+    }
+    terms
+}
+
+/// The power a sum is raised to, if it is a small enough positive integer for the sum to be multiplied out.
+#[allow(clippy::float_cmp)]
+fn expanded_power(base: &Expr, exponent: &Expr) -> Option<u32> {
+    match (base, exponent) {
+        (Expr::Add(..) | Expr::Sub(..), Expr::Constant(n)) => {
+            (1..=MAX_EXPANDED_POWER).find(|&power| f64::from(power) == *n)
+        }
+        _ => None,
+    }
+}
+
+/// Multiplies the factors of a product, raised to the given integer power, into `term`.
+///
+/// Only integer powers are multiplied into the factors of a product, as `(ab)^n = a^n b^n` is not true in general for other powers.
+/// Powers of powers are only merged when both exponents are integers, or when the outer one is `1` or `-1`,
+/// as `(a^m)^n = a^(mn)` is not true in general otherwise: `(x^0.5)^2` is not `x` for negative x.
+#[allow(clippy::float_cmp)]
+fn push_factors(expression: &Expr, power: f64, term: &mut Term) {
+    match expression {
+        Expr::Constant(c) => term.coefficient *= c.powf(power),
+        Expr::Mul(a, b) => {
+            push_factors(a, power, term);
+            push_factors(b, power, term);
+        }
+        Expr::Div(a, b) => {
+            push_factors(a, power, term);
+            push_factors(b, -power, term);
+        }
+        Expr::Pow(base, exponent) => match **exponent {
+            Expr::Constant(n) if n.fract() == 0_f64 => push_factors(base, power * n, term),
+            _ if **base == Expr::Constant(1_f64) => {}
+            _ if power.abs() == 1_f64 => term
+                .factors
+                .push((*base.clone(), scaled_exponent(exponent, power))),
+            _ => term
+                .factors
+                .push((expression.clone(), Expr::Constant(power))),
+        },
+        _ => term
+            .factors
+            .push((expression.clone(), Expr::Constant(power))),
+    }
+}
+
+/// Multiplies an exponent by `1` or `-1`.
+#[allow(clippy::float_cmp)]
+fn scaled_exponent(exponent: &Expr, power: f64) -> Expr {
+    match exponent {
+        Expr::Constant(n) => Expr::Constant(n * power),
+        _ if power == 1_f64 => exponent.clone(),
+        _ => simplify(&(Expr::Constant(power) * exponent.clone())),
+    }
+}
+
+/// Adds two exponents of the same base.
+fn add_exponents(a: &Expr, b: &Expr) -> Expr {
+    match (a, b) {
+        (Expr::Constant(m), Expr::Constant(n)) => Expr::Constant(m + n),
+        _ => simplify(&(a.clone() + b.clone())),
+    }
+}
+
+/// The values of a base for which a power of it is defined.
+#[derive(Clone, Copy, PartialEq)]
+enum Domain {
+    All,
+    NonZero,
+    NonNegative,
+    Positive,
+}
+
+impl Domain {
+    /// The values of the base for which `base^exponent` is defined, assuming that a power which is not a constant is only defined for positive bases.
+    #[allow(clippy::float_cmp)]
+    fn of(exponent: &Expr) -> Self {
+        match exponent {
+            Expr::Constant(n) if n.fract() == 0_f64 && *n >= 0_f64 => Self::All,
+            Expr::Constant(n) if n.fract() == 0_f64 => Self::NonZero,
+            Expr::Constant(n) if *n > 0_f64 => Self::NonNegative,
+            _ => Self::Positive,
+        }
+    }
+
+    /// The values of the base for which powers with both domains are defined.
+    fn intersection(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::All, domain) | (domain, Self::All) => domain,
+            (a, b) if a == b => a,
+            _ => Self::Positive,
+        }
+    }
+}
+
+/// Merges the powers of one base, as in `x^2 * x^(-1) * x^(-3) = x^(-2)`.
+///
+/// If the merged power would be defined for more values of the base than the product, as for `x * x^(-1) = x^0`, the product is kept as two powers instead:
+/// the sum of the exponents less one which has the same domain as the product, times the base to the power of that one.
+/// So `x / x` becomes `x * x^(-1)` and `x^0.5 / x^0.5` becomes `x^0.5 * x^(-0.5)`, which are still undefined at 0, and for negative x in the second case.
+/// Powers of bases which are always positive, such as `2^x`, are always merged.
+fn merged_powers(base: Expr, exponents: &[Expr]) -> Vec<(Expr, Expr)> {
+    let Some(sum) = exponents
+        .iter()
+        .cloned()
+        .reduce(|a, b| add_exponents(&a, &b))
+    else {
+        return vec![];
+    };
+    let domain = exponents
+        .iter()
+        .map(Domain::of)
+        .fold(Domain::All, Domain::intersection);
+    let is_positive =
+        matches!(base, Expr::Constant(c) if c > 0_f64) || matches!(base, Expr::Exp(_));
+    if is_positive || Domain::of(&sum) == domain {
+        return vec![(base, sum)];
+    }
+    let witness = match domain {
+        Domain::All => return vec![(base, sum)],
+        Domain::NonZero => -1_f64,
+        Domain::NonNegative => 0.5_f64,
+        Domain::Positive => -0.5_f64,
+    };
+    vec![
+        (base.clone(), add_exponents(&sum, &Expr::Constant(-witness))),
+        (base, Expr::Constant(witness)),
+    ]
+}
+
+/// Checks if a coefficient or exponent is exactly zero, as `Polynomial::simplified()` does.
+#[allow(clippy::float_cmp)]
+fn is_zero(value: f64) -> bool {
+    value == 0_f64
+}
+
+impl Term {
+    /// Sorts the factors of the term and merges the powers of equal bases, as in `x^2 * x^3 = x^5` and `exp(a) * exp(b) = exp(a + b)`, as far as `merged_powers()` allows.
+    fn normalized(self) -> Self {
+        let mut coefficient = self.coefficient;
+        let (exponentials, mut factors): (Vec<_>, Vec<_>) = self
+            .factors
+            .into_iter()
+            .partition(|(base, _)| matches!(base, Expr::Exp(_)));
+        let argument = exponentials
+            .into_iter()
+            .filter_map(|(base, exponent)| match base {
+                Expr::Exp(argument) => Some(exponent * *argument),
+                _ => None,
+            })
+            .reduce(Add::add);
+        if let Some(argument) = argument {
+            match simplify(&argument.exp()) {
+                Expr::Constant(c) => coefficient *= c,
+                exponential => factors.push((exponential, Expr::Constant(1_f64))),
+            }
+        }
+
+        factors.sort_by(|a, b| compare(&a.0, &b.0));
+        let mut merged: Vec<(Expr, Expr)> = vec![];
+        let mut factors = factors.into_iter().peekable();
+        while let Some((base, exponent)) = factors.next() {
+            let mut exponents = vec![exponent];
+            while let Some((_, exponent)) = factors.next_if(|(next, _)| *next == base) {
+                exponents.push(exponent);
+            }
+            merged.extend(merged_powers(base, &exponents));
+        }
+        merged.retain(|(_, exponent)| !matches!(exponent, Expr::Constant(n) if is_zero(*n)));
+
+        Self {
+            coefficient,
+            factors: merged,
+        }
+    }
+
+    /// Multiplies one term by another.
+    fn multiply(&self, other: &Self) -> Self {
+        Self {
+            coefficient: self.coefficient * other.coefficient,
+            factors: self.factors.iter().chain(&other.factors).cloned().collect(),
+        }
+        .normalized()
+    }
+
+    /// The term as a monomial, if it is a constant or a constant times a power of x.
+    fn to_monomial(&self) -> Option<Monomial> {
+        match self.factors.as_slice() {
+            [] => Some(Monomial {
+                c: self.coefficient,
+                e: 0_f64,
+            }),
+            [(Expr::X, Expr::Constant(e))] => Some(Monomial {
+                c: self.coefficient,
+                e: *e,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Converts a monomial into a term.
+    #[allow(clippy::float_cmp)]
+    fn from_monomial(monomial: &Monomial) -> Self {
+        Self {
+            coefficient: monomial.c,
+            factors: if monomial.e == 0_f64 {
+                vec![]
+            } else {
+                vec![(Expr::X, Expr::Constant(monomial.e))]
+            },
+        }
+    }
+
+    /// Converts the term into an expression, leaving out a coefficient of `1` and exponents of `1`.
+    #[allow(clippy::float_cmp)]
+    fn to_expr(&self) -> Expr {
+        let coefficient = (self.factors.is_empty() || self.coefficient != 1_f64)
+            .then_some(Expr::Constant(self.coefficient));
+        coefficient
+            .into_iter()
+            .chain(self.factors.iter().map(|(base, exponent)| {
+                if *exponent == Expr::Constant(1_f64) {
+                    base.clone()
+                } else {
+                    base.clone().pow(exponent.clone())
+                }
+            }))
+            .reduce(|a, b| a * b)
+            .unwrap_or(Expr::Constant(self.coefficient))
+    }
+}
+
+/// Combines like terms, leaving out the ones whose coefficients are zero, and puts them in canonical order.
+///
+/// The powers of x come first, in descending order, then the other terms, then the constant.
+fn combine(terms: Vec<Term>) -> Vec<Term> {
+    let mut monomials = vec![];
+    let mut others = vec![];
+    for term in terms {
+        match term.to_monomial() {
+            Some(monomial) => monomials.push(monomial),
+            None => others.push(term),
+        }
+    }
+
+    let polynomial = Polynomial(monomials);
+    let polynomial = polynomial.simplified().unwrap_or(polynomial);
+    let (constants, powers): (Vec<_>, Vec<_>) = polynomial
+        .0
+        .iter()
+        .map(Term::from_monomial)
+        .partition(|term| term.factors.is_empty());
+
+    others.sort_by(|a, b| compare_factors(&a.factors, &b.factors));
+    let mut combined: Vec<Term> = vec![];
+    for term in others {
+        match combined.last_mut() {
+            Some(last) if last.factors == term.factors => last.coefficient += term.coefficient,
+            _ => combined.push(term),
+        }
+    }
+    combined.retain(|term| !is_zero(term.coefficient));
+
+    powers
+        .into_iter()
+        .chain(combined)
+        .chain(constants)
+        .collect()
+}
+
+/// Replaces one pair of terms `a * sin(u)^2 * rest` and `b * cos(u)^2 * rest` by `b * rest + (a - b) * sin(u)^2 * rest`, if there is one.
+fn pythagorean_identity(terms: &[Term]) -> Option<Vec<Term>> {
+    for (i, term) in terms.iter().enumerate() {
+        for (k, (base, exponent)) in term.factors.iter().enumerate() {
+            let (Expr::Sin(argument), Expr::Constant(2_f64)) = (base, exponent) else {
+                continue;
+            };
+            let mut partner = term.factors.clone();
+            partner[k].0 = Expr::Cos(argument.clone());
+            partner.sort_by(|a, b| compare(&a.0, &b.0));
+            let Some(j) = terms.iter().position(|other| other.factors == partner) else {
+                continue;
+            };
+
+            let rest = term
+                .factors
+                .iter()
+                .enumerate()
+                .filter(|(n, _)| *n != k)
+                .map(|(_, factor)| factor.clone())
+                .collect();
+            let mut replaced: Vec<Term> = terms
+                .iter()
+                .enumerate()
+                .filter(|(n, _)| *n != i && *n != j)
+                .map(|(_, other)| other.clone())
+                .collect();
+            replaced.push(Term {
+                coefficient: terms[j].coefficient,
+                factors: rest,
+            });
+            replaced.push(Term {
+                coefficient: term.coefficient - terms[j].coefficient,
+                factors: term.factors.clone(),
+            });
+            return Some(replaced);
+        }
+    }
+    None
+}
+
+/// Applies the identity `cos(u)^2 = 1 - sin(u)^2` to combined terms which also have `sin(u)^2`, then adds them up into an expression.
+///
+/// A term with a negative coefficient is subtracted, unless it comes first.
+fn sum(mut terms: Vec<Term>) -> Expr {
+    while let Some(replaced) = pythagorean_identity(&terms) {
+        terms = combine(replaced);
+    }
+    terms
+        .iter()
+        .fold(None, |sum, term| match sum {
+            None => Some(term.to_expr()),
+            Some(sum) if term.coefficient < 0_f64 => Some(
+                sum - Term {
+                    coefficient: -term.coefficient,
+                    factors: term.factors.clone(),
+                }
+                .to_expr(),
+            ),
+            Some(sum) => Some(sum + term.to_expr()),
+        })
+        .unwrap_or(Expr::Constant(0_f64))
+}
+
+/// The position of each kind of expression in canonical order.
+fn rank(expression: &Expr) -> u8 {
+    match expression {
+        Expr::Constant(_) => 0,
+        Expr::X => 1,
+        Expr::Add(..) => 2,
+        Expr::Sub(..) => 3,
+        Expr::Mul(..) => 4,
+        Expr::Div(..) => 5,
+        Expr::Pow(..) => 6,
+        Expr::Sin(_) => 7,
+        Expr::Cos(_) => 8,
+        Expr::Tan(_) => 9,
+        Expr::Exp(_) => 10,
+        Expr::Ln(_) => 11,
+    }
+}
+
+/// A total order on expressions, which sorts the factors and terms of canonical form.
+fn compare(a: &Expr, b: &Expr) -> Ordering {
+    match (a, b) {
+        (Expr::Constant(m), Expr::Constant(n)) => m.total_cmp(n),
+        (Expr::Add(a1, a2), Expr::Add(b1, b2))
+        | (Expr::Sub(a1, a2), Expr::Sub(b1, b2))
+        | (Expr::Mul(a1, a2), Expr::Mul(b1, b2))
+        | (Expr::Div(a1, a2), Expr::Div(b1, b2))
+        | (Expr::Pow(a1, a2), Expr::Pow(b1, b2)) => compare(a1, b1).then_with(|| compare(a2, b2)),
+        (Expr::Sin(a), Expr::Sin(b))
+        | (Expr::Cos(a), Expr::Cos(b))
+        | (Expr::Tan(a), Expr::Tan(b))
+        | (Expr::Exp(a), Expr::Exp(b))
+        | (Expr::Ln(a), Expr::Ln(b)) => compare(a, b),
+        _ => rank(a).cmp(&rank(b)),
+    }
+}
+
+/// Compares the factors of two terms, base by base and then exponent by exponent.
+fn compare_factors(a: &[(Expr, Expr)], b: &[(Expr, Expr)]) -> Ordering {
+    a.iter()
+        .zip(b)
+        .map(|((a_base, a_exponent), (b_base, b_exponent))| {
+            compare(a_base, b_base).then_with(|| compare(a_exponent, b_exponent))
+        })
+        .find(|ordering| ordering.is_ne())
+        .unwrap_or_else(|| a.len().cmp(&b.len()))
+}
@@ -1,9 +1,19 @@
+mod test_analysis;
+mod test_dense;
+mod test_error;
+mod test_expr;
 mod test_monomial;
+mod test_parse;
+mod test_rational;
 mod test_polynomial;
+mod test_roots;
+mod test_scalar;
+mod test_tolerance;
 
 use crate::Monomial;
 use std::f64::consts::{E, PI};
 
+#[allow(clippy::unreadable_literal)]
 mod monomial_statics {
     use super::*;
 
@@ -20,6 +30,7 @@ mod monomial_statics {
     pub static MONOMIAL_1_0: Monomial = Monomial { c: 1_f64, e: 0_f64 };
     pub static MONOMIAL_1_1: Monomial = Monomial { c: 1_f64, e: 1_f64 };
     pub static MONOMIAL_1_2: Monomial = Monomial { c: 1_f64, e: 2_f64 };
+    pub static MONOMIAL_1_3: Monomial = Monomial { c: 1_f64, e: 3_f64 };
     pub static MONOMIAL_1_N1: Monomial = Monomial {
         c: 1_f64,
         e: -1_f64,
@@ -0,0 +1,319 @@
+use crate::{
+    Concavity, ConcavityInterval, CriticalPoint, CriticalPointKind, Extremum, InflectionPoint,
+    Monomial, MonotonicInterval, Polynomial, Trend,
+};
+
+fn assert_intervals(actual: &[MonotonicInterval], expected: &[(f64, f64, Trend)]) {
+    assert_eq!(actual.len(), expected.len(), "{actual:?}");
+    for (interval, (start, end, trend)) in actual.iter().zip(expected) {
+        assert!((interval.start - start).abs() < 1e-9, "{actual:?}");
+        assert!((interval.end - end).abs() < 1e-9, "{actual:?}");
+        assert_eq!(interval.trend, *trend, "{actual:?}");
+    }
+}
+
+#[test]
+fn test_monotonicity() {
+    // x^3 - 3x has critical points at -1 and 1.
+    let p1 = Polynomial(vec![
+        Monomial { c: 1.0, e: 3.0 },
+        Monomial { c: -3.0, e: 1.0 },
+    ]);
+    assert_intervals(
+        &p1.monotonicity(-3.0, 3.0).unwrap(),
+        &[
+            (-3.0, -1.0, Trend::Increasing),
+            (-1.0, 1.0, Trend::Decreasing),
+            (1.0, 3.0, Trend::Increasing),
+        ],
+    );
+    assert_intervals(
+        &p1.monotonicity(3.0, 0.0).unwrap(),
+        &[(0.0, 1.0, Trend::Decreasing), (1.0, 3.0, Trend::Increasing)],
+    );
+    // A critical point at the end of the interval does not split it.
+    assert_intervals(
+        &p1.monotonicity(-1.0, 1.0).unwrap(),
+        &[(-1.0, 1.0, Trend::Decreasing)],
+    );
+
+    // x^4 - 2x^3 has a critical point at 0 where the derivative does not change sign.
+    let p2 = Polynomial(vec![
+        Monomial { c: 1.0, e: 4.0 },
+        Monomial { c: -2.0, e: 3.0 },
+    ]);
+    assert_intervals(
+        &p2.monotonicity(-1.0, 2.0).unwrap(),
+        &[
+            (-1.0, 1.5, Trend::Decreasing),
+            (1.5, 2.0, Trend::Increasing),
+        ],
+    );
+
+    let p3 = Polynomial(vec![Monomial { c: 4.0, e: 0.0 }]);
+    assert_intervals(
+        &p3.monotonicity(-1.0, 1.0).unwrap(),
+        &[(-1.0, 1.0, Trend::Constant)],
+    );
+    assert_intervals(
+        &Polynomial::new().monotonicity(-1.0, 1.0).unwrap(),
+        &[(-1.0, 1.0, Trend::Constant)],
+    );
+}
+
+#[test]
+fn test_monotonicity_with_singularity() {
+    // 1/x decreases on both sides of 0, but not across it.
+    let p1 = Polynomial(vec![Monomial { c: 1.0, e: -1.0 }]);
+    assert_intervals(
+        &p1.monotonicity(-1.0, 1.0).unwrap(),
+        &[
+            (-1.0, 0.0, Trend::Decreasing),
+            (0.0, 1.0, Trend::Decreasing),
+        ],
+    );
+    assert_eq!(p1.trend_over_interval(-1.0, 1.0), Trend::Mixed);
+    assert_eq!(p1.trend_over_interval(1.0, 2.0), Trend::Decreasing);
+
+    // x + 1/x has critical points at -1 and 1.
+    let p2 = Polynomial(vec![
+        Monomial { c: 1.0, e: 1.0 },
+        Monomial { c: 1.0, e: -1.0 },
+    ]);
+    assert_intervals(
+        &p2.monotonicity(-2.0, 2.0).unwrap(),
+        &[
+            (-2.0, -1.0, Trend::Increasing),
+            (-1.0, 0.0, Trend::Decreasing),
+            (0.0, 1.0, Trend::Decreasing),
+            (1.0, 2.0, Trend::Increasing),
+        ],
+    );
+}
+
+#[test]
+fn test_monotonicity_errors() {
+    let p1 = Polynomial(vec![Monomial { c: 1.0, e: 2.5 }]);
+    assert!(p1.monotonicity(0.0, 1.0).is_err());
+}
+
+#[test]
+fn test_trend_over_interval_mixed() {
+    let p1 = Polynomial(vec![Monomial { c: 1.0, e: 2.0 }]);
+    assert_eq!(p1.trend_over_interval(-1.0, 2.0), Trend::Mixed);
+    assert_eq!(p1.trend_over_interval(-1.0, 1.0), Trend::Mixed);
+    assert_eq!(p1.trend_over_interval(0.0, 2.0), Trend::Increasing);
+    assert_eq!(p1.trend_over_interval(-2.0, 0.0), Trend::Decreasing);
+    assert_eq!(Trend::Mixed.to_string(), "mixed");
+}
+
+fn assert_concavity_intervals(actual: &[ConcavityInterval], expected: &[(f64, f64, Concavity)]) {
+    assert_eq!(actual.len(), expected.len(), "{actual:?}");
+    for (interval, (start, end, concavity)) in actual.iter().zip(expected) {
+        assert!((interval.start - start).abs() < 1e-9, "{actual:?}");
+        assert!((interval.end - end).abs() < 1e-9, "{actual:?}");
+        assert_eq!(interval.concavity, *concavity, "{actual:?}");
+    }
+}
+
+#[test]
+fn test_concavity() {
+    // x^4 - 6x^2 has inflection points at -1 and 1.
+    let p1 = Polynomial(vec![
+        Monomial { c: 1.0, e: 4.0 },
+        Monomial { c: -6.0, e: 2.0 },
+    ]);
+    let analysis = p1.concavity(3.0, -3.0).unwrap();
+    assert_concavity_intervals(
+        &analysis.intervals,
+        &[
+            (-3.0, -1.0, Concavity::ConcaveUp),
+            (-1.0, 1.0, Concavity::ConcaveDown),
+            (1.0, 3.0, Concavity::ConcaveUp),
+        ],
+    );
+    assert_eq!(analysis.inflection_points.len(), 2);
+    for (point, x) in analysis.inflection_points.iter().zip([-1.0, 1.0]) {
+        assert!((point.x - x).abs() < 1e-9);
+        assert!((point.y + 5.0).abs() < 1e-9);
+        assert!(point.sign_changes);
+    }
+
+    // The endpoints are both concave up, but the concavity flips twice in between.
+    assert_eq!(
+        p1.concavity_over_interval(-3.0, 3.0).unwrap(),
+        Concavity::Undefined
+    );
+    assert_eq!(
+        p1.concavity_over_interval(-0.5, 0.5).unwrap(),
+        Concavity::ConcaveDown
+    );
+
+    // x^5 has a root of the second derivative at 0, where it changes sign.
+    let p2 = Polynomial(vec![Monomial { c: 1.0, e: 5.0 }]);
+    let analysis = p2.concavity(-1.0, 1.0).unwrap();
+    assert_eq!(
+        analysis.inflection_points,
+        vec![InflectionPoint {
+            x: 0.0,
+            y: 0.0,
+            sign_changes: true
+        }]
+    );
+    assert_eq!(p2.concavity(0.0, 1.0).unwrap().inflection_points, vec![]);
+
+    // A straight line has no concavity.
+    let p3 = Polynomial(vec![Monomial { c: 2.0, e: 1.0 }]);
+    let analysis = p3.concavity(-1.0, 1.0).unwrap();
+    assert_concavity_intervals(&analysis.intervals, &[(-1.0, 1.0, Concavity::Undefined)]);
+    assert!(analysis.inflection_points.is_empty());
+}
+
+#[test]
+fn test_concavity_with_singularity() {
+    // 1/x is concave down for negative x and concave up for positive x, but 0 is not an inflection point.
+    let p1 = Polynomial(vec![Monomial { c: 1.0, e: -1.0 }]);
+    let analysis = p1.concavity(-1.0, 1.0).unwrap();
+    assert_concavity_intervals(
+        &analysis.intervals,
+        &[
+            (-1.0, 0.0, Concavity::ConcaveDown),
+            (0.0, 1.0, Concavity::ConcaveUp),
+        ],
+    );
+    assert!(analysis.inflection_points.is_empty());
+
+    // 1/x^2 is concave up on both sides of 0.
+    let p2 = Polynomial(vec![Monomial { c: 1.0, e: -2.0 }]);
+    let analysis = p2.concavity(-1.0, 1.0).unwrap();
+    assert_eq!(analysis.intervals.len(), 2);
+    assert!(
+        analysis
+            .intervals
+            .iter()
+            .all(|interval| interval.concavity == Concavity::ConcaveUp)
+    );
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_critical_points_higher_derivatives() {
+    // (x - 1)^4 = x^4 - 4x^3 + 6x^2 - 4x + 1 has a minimum at 1, found with the fourth derivative.
+    let p1 = Polynomial(vec![
+        Monomial { c: 1.0, e: 4.0 },
+        Monomial { c: -4.0, e: 3.0 },
+        Monomial { c: 6.0, e: 2.0 },
+        Monomial { c: -4.0, e: 1.0 },
+        Monomial { c: 1.0, e: 0.0 },
+    ]);
+    let points = p1.critical_points(-3.0, 3.0).unwrap();
+    assert_eq!(points.len(), 1);
+    assert!((points[0].x - 1.0).abs() < 1e-6);
+    assert!(points[0].y.abs() < 1e-12);
+    assert_eq!(points[0].kind, CriticalPointKind::LocalMinimum);
+
+    let p2 = Polynomial(vec![Monomial { c: -2.0, e: 6.0 }]);
+    assert_eq!(
+        p2.critical_points(-1.0, 1.0).unwrap(),
+        vec![CriticalPoint {
+            x: 0.0,
+            y: 0.0,
+            kind: CriticalPointKind::LocalMaximum
+        }]
+    );
+
+    let p3 = Polynomial(vec![Monomial { c: 1.0, e: 5.0 }]);
+    assert_eq!(
+        p3.critical_points(-1.0, 1.0).unwrap()[0].kind,
+        CriticalPointKind::Saddle
+    );
+
+    // Critical points at the ends of the interval are included, those outside are not.
+    let p4 = Polynomial(vec![
+        Monomial { c: 1.0, e: 3.0 },
+        Monomial { c: -3.0, e: 1.0 },
+    ]);
+    let points = p4.critical_points(1.0, -1.0).unwrap();
+    assert_eq!(points.len(), 2);
+    assert_eq!(points[0].x, -1.0);
+    assert_eq!(points[1].x, 1.0);
+    assert!(p4.critical_points(2.0, 3.0).unwrap().is_empty());
+
+    let p5 = Polynomial(vec![Monomial { c: 3.0, e: 0.0 }]);
+    assert!(p5.critical_points(-1.0, 1.0).unwrap().is_empty());
+
+    let p6 = Polynomial(vec![Monomial { c: 1.0, e: 1.5 }]);
+    assert!(p6.critical_points(0.0, 1.0).is_err());
+    assert_eq!(CriticalPointKind::LocalMaximum.to_string(), "local maximum");
+}
+
+#[test]
+fn test_extrema() {
+    // -x^4 + 2x^2 has local maxima at -1 and 1 and a local minimum at 0.
+    let p1 = Polynomial(vec![
+        Monomial { c: -1.0, e: 4.0 },
+        Monomial { c: 2.0, e: 2.0 },
+    ]);
+    let extrema = p1.extrema(-2.0, 1.5).unwrap();
+    let kinds: Vec<CriticalPointKind> = extrema
+        .critical_points
+        .iter()
+        .map(|point| point.kind)
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![
+            CriticalPointKind::LocalMaximum,
+            CriticalPointKind::LocalMinimum,
+            CriticalPointKind::LocalMaximum
+        ]
+    );
+    assert_eq!(extrema.absolute_maximum, Some(Extremum { x: -1.0, y: 1.0 }));
+    assert_eq!(
+        extrema.absolute_minimum,
+        Some(Extremum { x: -2.0, y: -8.0 })
+    );
+
+    // Without critical points, the extrema are at the endpoints.
+    let p2 = Polynomial(vec![Monomial { c: -2.0, e: 1.0 }]);
+    let extrema = p2.extrema(3.0, 1.0).unwrap();
+    assert!(extrema.critical_points.is_empty());
+    assert_eq!(extrema.absolute_maximum, Some(Extremum { x: 1.0, y: -2.0 }));
+    assert_eq!(extrema.absolute_minimum, Some(Extremum { x: 3.0, y: -6.0 }));
+}
+
+#[test]
+fn test_extrema_with_singularity() {
+    // 1/x tends to infinity as x approaches 0 from the right.
+    let p1 = Polynomial(vec![Monomial { c: 1.0, e: -1.0 }]);
+    let extrema = p1.extrema(0.0, 2.0).unwrap();
+    assert_eq!(extrema.absolute_maximum, None);
+    assert_eq!(extrema.absolute_minimum, Some(Extremum { x: 2.0, y: 0.5 }));
+    let extrema = p1.extrema(-1.0, 2.0).unwrap();
+    assert_eq!(extrema.absolute_maximum, None);
+    assert_eq!(extrema.absolute_minimum, None);
+
+    // x + 1/x has a local maximum at -1 and a local minimum at 1.
+    let p2 = Polynomial(vec![
+        Monomial { c: 1.0, e: 1.0 },
+        Monomial { c: 1.0, e: -1.0 },
+    ]);
+    let extrema = p2.extrema(0.5, 4.0).unwrap();
+    assert_eq!(
+        extrema.critical_points,
+        vec![CriticalPoint {
+            x: 1.0,
+            y: 2.0,
+            kind: CriticalPointKind::LocalMinimum
+        }]
+    );
+    assert_eq!(extrema.absolute_maximum, Some(Extremum { x: 4.0, y: 4.25 }));
+    let extrema = p2.extrema(-2.0, 2.0).unwrap();
+    assert_eq!(extrema.critical_points.len(), 2);
+    assert_eq!(
+        extrema.critical_points[0].kind,
+        CriticalPointKind::LocalMaximum
+    );
+    assert_eq!(extrema.absolute_maximum, None);
+    assert_eq!(extrema.absolute_minimum, None);
+}
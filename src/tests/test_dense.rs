@@ -0,0 +1,157 @@
+use crate::{CalcError, DensePolynomial, DomainError, Monomial, Polynomial};
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_dense_polynomial_value() {
+    let p1 = Polynomial(vec![
+        Monomial { c: 0.5, e: 4.0 },
+        Monomial { c: -3.0, e: 2.0 },
+        Monomial { c: 2.0, e: 1.0 },
+        Monomial { c: 7.0, e: 0.0 },
+    ]);
+    let d1 = DensePolynomial::try_from(&p1).unwrap();
+    assert_eq!(d1, DensePolynomial(vec![7.0, 2.0, -3.0, 0.0, 0.5]));
+    assert_eq!(d1.degree(), Some(4));
+    for x in [-3.0, -1.5, 0.0, 0.25, 2.0, 10.0] {
+        assert_eq!(d1.value(x), p1.value(x));
+    }
+
+    assert_eq!(
+        Polynomial::from(&d1.derivative()),
+        p1.derivative().unwrap().simplified().unwrap()
+    );
+    assert_eq!(DensePolynomial::default().degree(), None);
+    assert_eq!(DensePolynomial::default().value(2.0), 0.0);
+}
+
+#[test]
+fn test_dense_polynomial_conversions() {
+    let p1 = Polynomial(vec![
+        Monomial { c: 2.0, e: 1.0 },
+        Monomial { c: 1.0, e: 3.0 },
+        Monomial { c: 4.0, e: 1.0 },
+        Monomial { c: 0.0, e: 2.0 },
+    ]);
+    let d1 = DensePolynomial::try_from(p1.clone()).unwrap();
+    assert_eq!(d1, DensePolynomial(vec![0.0, 6.0, 0.0, 1.0]));
+    assert_eq!(Polynomial::from(d1), p1.simplified().unwrap());
+
+    let p2 = Polynomial::from(DensePolynomial(vec![0.1, 0.0, -0.3, 0.0]));
+    assert_eq!(
+        p2,
+        Polynomial(vec![
+            Monomial { c: -0.3, e: 2.0 },
+            Monomial { c: 0.1, e: 0.0 },
+        ])
+    );
+    assert_eq!(
+        DensePolynomial::try_from(&p2).unwrap(),
+        DensePolynomial(vec![0.1, 0.0, -0.3])
+    );
+    assert_eq!(
+        DensePolynomial::try_from(Polynomial::new()),
+        Ok(DensePolynomial(vec![]))
+    );
+
+    assert_eq!(
+        DensePolynomial::try_from(Polynomial(vec![Monomial { c: 1.0, e: -2.0 }])),
+        Err(CalcError::Domain(DomainError::NegativeExponent {
+            exponent: -2.0
+        }))
+    );
+    assert_eq!(
+        DensePolynomial::try_from(Polynomial(vec![Monomial { c: 1.0, e: 1.5 }])),
+        Err(CalcError::Domain(DomainError::NonIntegerExponent {
+            exponent: 1.5
+        }))
+    );
+    assert_eq!(
+        DensePolynomial::try_from(Polynomial(vec![Monomial { c: 1.0, e: 1e9 }])),
+        Err(CalcError::Domain(DomainError::DegreeTooLarge {
+            degree: 1e9
+        }))
+    );
+}
+
+#[test]
+fn test_dense_polynomial_multiplication() {
+    // Integer coefficients are multiplied exactly by the naive and Karatsuba algorithms.
+    for (m, n) in [
+        (0_u32, 5_u32),
+        (3, 5),
+        (31, 40),
+        (32, 32),
+        (33, 100),
+        (100, 1000),
+        (257, 63),
+    ] {
+        let a = DensePolynomial((0..m).map(|i| f64::from(i % 7) - 3.0).collect());
+        let b = DensePolynomial((0..n).map(|i| f64::from(i % 5) + 1.0).collect());
+        let length = if m == 0 { 0 } else { m + n - 1 };
+        let mut expected = vec![0.0; usize::try_from(length).unwrap()];
+        for (i, x) in a.0.iter().enumerate() {
+            for (j, y) in b.0.iter().enumerate() {
+                expected[i + j] += x * y;
+            }
+        }
+        assert_eq!(a.multiply_polynomial(&b), DensePolynomial(expected.clone()));
+        assert_eq!(&b * &a, DensePolynomial(expected));
+    }
+
+    // The FFT is accurate to within rounding.
+    let a = DensePolynomial(vec![1.0; 5000]);
+    let product = a.clone() * a;
+    assert_eq!(product.0.len(), 9999);
+    for (i, c) in product.0.iter().enumerate() {
+        let expected = f64::from(u32::try_from(i.min(9998 - i)).unwrap() + 1);
+        assert!((c - expected).abs() < 1e-6, "coefficient {i} is {c}");
+    }
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_dense_polynomial_values() {
+    let d1 = DensePolynomial(vec![7.0, 2.0, -3.0, 0.0, 0.5]);
+    let xs: Vec<f64> = (0..21).map(|i| f64::from(i) * 0.5 - 5.0).collect();
+    let values = d1.values(&xs);
+    assert_eq!(values.len(), xs.len());
+    for (x, value) in xs.iter().zip(&values) {
+        assert_eq!(*value, d1.value(*x));
+    }
+
+    let mut values = vec![1.0; 3];
+    DensePolynomial::default().evaluate_into(&[1.0, 2.0, 3.0], &mut values);
+    assert_eq!(values, vec![0.0; 3]);
+    assert!(d1.values(&[]).is_empty());
+}
+
+#[test]
+#[should_panic(expected = "same length")]
+fn test_dense_polynomial_evaluate_into_length_mismatch() {
+    DensePolynomial(vec![1.0]).evaluate_into(&[1.0, 2.0], &mut [0.0]);
+}
+
+#[test]
+fn test_dense_polynomial_values_of_high_degree() {
+    // Many points for a polynomial of high degree are evaluated by re-centring it, to within the rounding of Horner's method.
+    let d1 = DensePolynomial((0..300).map(|i| f64::from(i % 11) - 5.0).collect());
+    let mut xs: Vec<f64> = (0..1500)
+        .map(|i| (f64::from(i) * 0.618_034).fract() * 2.5 - 1.25)
+        .collect();
+    xs.extend([0.0, -0.0, 1.0, 5.0, f64::NAN, f64::INFINITY]);
+    let values = d1.values(&xs);
+    for (x, value) in xs.iter().zip(&values) {
+        let bound =
+            d1.0.iter()
+                .rev()
+                .fold(0.0, |sum, c| sum * x.abs() + c.abs());
+        if x.is_finite() {
+            assert!(
+                (value - d1.value(*x)).abs() <= 1e-12 * bound,
+                "the value at {x} is {value}"
+            );
+        } else {
+            assert!(value.is_nan());
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use std::error::Error;
+
+use crate::tests::monomial_statics::*;
+use crate::{CalcError, DomainError, ParseError, ParseErrorKind, Polynomial};
+
+#[test]
+fn test_calc_error_mismatched_exponents() {
+    let error = MONOMIAL_1_1
+        .add_monomial_of_same_power(&MONOMIAL_2_2)
+        .unwrap_err();
+    assert_eq!(
+        error,
+        CalcError::MismatchedExponents {
+            left: 1.0,
+            right: 2.0
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "Cannot add monomials with different powers of x (x^1 and x^2)."
+    );
+    assert!(error.source().is_none());
+}
+
+#[test]
+fn test_calc_error_domain() {
+    let error = MONOMIAL_1_N1.antiderivative().unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "Cannot integrate 1x^-1 with the power rule; its antiderivative is 1ln|x|."
+    );
+    assert_eq!(
+        error.source().unwrap().to_string(),
+        DomainError::LogarithmicTerm { coefficient: 1.0 }.to_string()
+    );
+
+    let error = Polynomial::new().complex_roots().unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "The zero polynomial has infinitely many roots."
+    );
+}
+
+#[test]
+fn test_calc_error_from() {
+    assert_eq!(
+        CalcError::from(DomainError::DivisionByZero),
+        CalcError::Domain(DomainError::DivisionByZero)
+    );
+
+    let parse_error = "x +".parse::<Polynomial>().unwrap_err();
+    let error = CalcError::from(parse_error.clone());
+    assert_eq!(
+        error,
+        CalcError::Parse(ParseError {
+            position: 3,
+            kind: ParseErrorKind::UnexpectedEnd
+        })
+    );
+    assert_eq!(
+        error.to_string(),
+        "Could not parse the input: unexpected end of input at position 3."
+    );
+    assert_eq!(error.source().unwrap().to_string(), parse_error.to_string());
+}
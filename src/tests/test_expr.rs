@@ -0,0 +1,325 @@
+use crate::{Expr, Monomial, Polynomial};
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_expr_value() {
+    // (sin(x) + cos(x)^2) / exp(x) - ln(x) * tan(x)
+    let e1 = (Expr::X.sin() + Expr::X.cos().pow(Expr::from(2.0))) / Expr::X.exp()
+        - Expr::X.ln() * Expr::X.tan();
+    for x in [0.5_f64, 1.0, 2.0, 3.5] {
+        let expected = (x.sin() + x.cos().powf(2.0)) / x.exp() - x.ln() * x.tan();
+        assert_eq!(e1.value(x), expected);
+    }
+
+    assert_eq!(Expr::Constant(4.5).value(-3.0), 4.5);
+    assert_eq!(Expr::X.value(-3.0), -3.0);
+    assert_eq!((-Expr::X).value(2.0), -2.0);
+    assert!(Expr::X.ln().value(-1.0).is_nan());
+    assert!((Expr::from(1.0) / Expr::X).value(0.0).is_infinite());
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_expr_from_monomial_and_polynomial() {
+    let m1 = Monomial { c: 3.0, e: 2.0 };
+    assert_eq!(
+        Expr::from(&m1),
+        Expr::Mul(
+            Box::new(Expr::Constant(3.0)),
+            Box::new(Expr::Pow(Box::new(Expr::X), Box::new(Expr::Constant(2.0))))
+        )
+    );
+    assert_eq!(Expr::from(m1.clone()), Expr::from(&m1));
+
+    let p1 = Polynomial(vec![
+        Monomial { c: 0.5, e: 4.0 },
+        Monomial { c: -3.0, e: 0.5 },
+        Monomial { c: 2.0, e: -1.0 },
+        Monomial { c: 7.0, e: 0.0 },
+    ]);
+    let e1 = Expr::from(&p1);
+    assert_eq!(
+        e1.to_string(),
+        "0.5 * x^4 + (-3 * x^0.5) + 2 * x^(-1) + 7 * x^0"
+    );
+    for x in [0.25, 1.0, 2.0, 9.0] {
+        assert_eq!(e1.value(x), p1.value(x));
+    }
+    assert_eq!(Expr::from(p1.clone()), e1);
+    assert_eq!(Expr::from(Polynomial::new()), Expr::Constant(0.0));
+}
+
+#[test]
+fn test_expr_display() {
+    assert_eq!(Expr::X.to_string(), "x");
+    assert_eq!(Expr::from(-2.5).to_string(), "-2.5");
+    assert_eq!(
+        (Expr::X * (Expr::X + Expr::from(1.0))).to_string(),
+        "x * (x + 1)"
+    );
+    assert_eq!(
+        (Expr::X * Expr::X + Expr::from(1.0)).to_string(),
+        "x * x + 1"
+    );
+    assert_eq!(
+        ((Expr::X - Expr::from(1.0)) - (Expr::X - Expr::from(2.0))).to_string(),
+        "x - 1 - (x - 2)"
+    );
+    assert_eq!(
+        (Expr::X / (Expr::X * Expr::from(2.0))).to_string(),
+        "x / (x * 2)"
+    );
+    assert_eq!(
+        Expr::X.pow(Expr::X).pow(Expr::from(2.0)).to_string(),
+        "(x^x)^2"
+    );
+    assert_eq!(
+        Expr::X.pow(Expr::X.pow(Expr::from(2.0))).to_string(),
+        "x^x^2"
+    );
+    assert_eq!(
+        (Expr::X + Expr::from(1.0))
+            .pow(Expr::from(-0.5))
+            .to_string(),
+        "(x + 1)^(-0.5)"
+    );
+    assert_eq!(Expr::from(-1.0).pow(Expr::X).to_string(), "(-1)^x");
+    assert_eq!((-Expr::X.sin()).to_string(), "-1 * sin(x)");
+    assert_eq!(
+        (Expr::X.cos() - Expr::X.sin() * Expr::from(-3.0)).to_string(),
+        "cos(x) - sin(x) * (-3)"
+    );
+    assert_eq!((Expr::X + -Expr::X.exp()).to_string(), "x + (-1 * exp(x))");
+    assert_eq!((Expr::X.tan() + Expr::X).ln().to_string(), "ln(tan(x) + x)");
+}
+
+#[test]
+fn test_expr_derivative() {
+    let x = Expr::X;
+    let two = Expr::from(2.0);
+    let cases = [
+        (x.clone().sin(), "cos(x)"),
+        (x.clone().cos(), "-1 * sin(x)"),
+        (x.clone().tan(), "1 / cos(x)^2"),
+        (x.clone().exp(), "exp(x)"),
+        (x.clone().ln(), "1 / x"),
+        ((two.clone() * x.clone()).exp(), "exp(2 * x) * (2 * 1)"),
+        (two.clone().pow(x.clone()), "2^x * ln(2)"),
+        (x.clone().pow(two.clone()), "2 * x^1"),
+        (x.clone().pow(x.clone()), "x^x * (1 * ln(x) + x * 1 / x)"),
+        (
+            x.clone().sin() / x.clone(),
+            "(cos(x) * x - sin(x) * 1) / x^2",
+        ),
+        (
+            two.clone() / x.clone().exp(),
+            "-1 * (2 * exp(x)) / exp(x)^2",
+        ),
+        (
+            x.clone().sin().pow(two.clone()).ln(),
+            "2 * sin(x)^1 * cos(x) / sin(x)^2",
+        ),
+        (Expr::from(3.0) - x.clone().cos(), "-1 * (-1 * sin(x))"),
+        (two.clone().ln(), "0"),
+    ];
+    for (expression, expected) in cases {
+        assert_eq!(expression.derivative().to_string(), expected);
+    }
+
+    // Compare with central differences, away from any singularities.
+    let e1 = (Expr::from(3.0) * x.clone().pow(two.clone())).sin() * x.clone().exp()
+        + x.clone().pow(x.clone().cos()) / (x.clone().tan() + two.clone()).ln();
+    let d1 = e1.derivative();
+    for point in [0.3_f64, 0.7, 1.1] {
+        let h = 1e-6;
+        let estimate = (e1.value(point + h) - e1.value(point - h)) / (2.0 * h);
+        assert!((d1.value(point) - estimate).abs() < 1e-6);
+    }
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_expr_derivative_matches_polynomial() {
+    let p1 = Polynomial(vec![
+        Monomial { c: 0.5, e: 4.0 },
+        Monomial { c: -3.0, e: 0.5 },
+        Monomial { c: 2.0, e: -1.0 },
+        Monomial { c: 7.0, e: 0.0 },
+    ]);
+    // Constant terms are x^0, whose derivative must be defined at 0.
+    let p2 = Polynomial(vec![
+        Monomial { c: 0.5, e: 4.0 },
+        Monomial { c: -3.0, e: 1.0 },
+        Monomial { c: 7.0, e: 0.0 },
+    ]);
+    for (polynomial, xs) in [
+        (&p1, [0.25_f64, 1.0, 2.0, 9.0]),
+        (&p2, [0.0, 0.25, 1.0, -2.0]),
+    ] {
+        let expression = Expr::from(polynomial);
+        for n in 0..4 {
+            let polynomial_derivative = polynomial.nth_derivative(n).unwrap();
+            let expression_derivative = expression.nth_derivative(n);
+            for x in xs {
+                let expected = polynomial_derivative.value(x);
+                assert!(
+                    (expression_derivative.value(x) - expected).abs() <= 1e-12 * expected.abs(),
+                    "the derivative {n} at {x} is {}",
+                    expression_derivative.value(x)
+                );
+            }
+        }
+    }
+    assert_eq!(Expr::from(&p1).derivative().value(1.0), 2.0 - 1.5 - 2.0);
+}
+
+#[test]
+fn test_expr_simplify() {
+    let x = Expr::X;
+    let c = Expr::from;
+    let cases = [
+        (
+            c(0.0) * x.clone() + c(1.0) * x.clone().cos() * c(1.0),
+            "cos(x)",
+        ),
+        (c(2.0).ln() * c(0.0) + c(3.0) * c(4.0), "12"),
+        (x.clone() + x.clone() + c(2.0) * x.clone(), "4 * x"),
+        (
+            x.clone().pow(c(2.0)) * x.clone() / x.clone().pow(c(4.0)),
+            "x^(-1)",
+        ),
+        (x.clone() / x.clone(), "x * x^(-1)"),
+        (x.clone().pow(c(3.0)) / x.clone(), "x^3 * x^(-1)"),
+        (x.clone().pow(c(-2.0)) * x.clone(), "x^(-1)"),
+        (x.clone().pow(x.clone()) * x.clone(), "x^(x + 1)"),
+        ((x.clone() + c(1.0)).pow(c(2.0)), "x^2 + 2 * x + 1"),
+        (
+            (x.clone() - c(1.0)) * (x.clone() - c(2.0)) * c(-1.0),
+            "-1 * x^2 + 3 * x - 2",
+        ),
+        (
+            x.clone().exp() * (c(2.0) * x.clone()).exp() / (x.clone() - c(1.0)).exp(),
+            "exp(2 * x + 1)",
+        ),
+        (x.clone().exp() / x.clone().exp(), "1"),
+        (x.clone().exp().ln(), "x"),
+        (
+            c(3.0) * x.clone().sin().pow(c(2.0)) + c(3.0) * x.clone().cos().pow(c(2.0)),
+            "3",
+        ),
+        (
+            x.clone() * x.clone().cos().pow(c(2.0))
+                + x.clone().sin().pow(c(2.0)) * x.clone()
+                + c(1.0),
+            "x + 1",
+        ),
+        (
+            x.clone().sin().pow(c(2.0)) + c(2.0) * x.clone().cos().pow(c(2.0)),
+            "-1 * sin(x)^2 + 2",
+        ),
+        (
+            x.clone().sin() * c(2.0) - x.clone() + x.clone().sin() * (x.clone() + c(1.0)),
+            "-1 * x + x * sin(x) + 3 * sin(x)",
+        ),
+        (x.clone().pow(c(2.0)).pow(c(0.5)), "(x^2)^0.5"),
+        (x.clone().pow(c(0.5)).pow(c(2.0)), "(x^0.5)^2"),
+        (
+            x.clone().pow(c(0.5)) / x.clone().pow(c(0.5)),
+            "x^0.5 * x^(-0.5)",
+        ),
+        (
+            x.clone().pow(c(0.5)) * x.clone().pow(c(0.5)),
+            "x^0.5 * x^0.5",
+        ),
+        (c(2.0).pow(x.clone()) / c(2.0).pow(x.clone()), "1"),
+        (c(1e-12) * x.clone().sin(), "0.000000000001 * sin(x)"),
+        (c(1.0).pow(x.clone()) + c(0.0).ln(), "ln(0) + 1"),
+    ];
+    for (expression, expected) in cases {
+        let simplified = expression.simplify();
+        assert_eq!(simplified.to_string(), expected);
+        assert_eq!(simplified.simplify(), simplified);
+    }
+}
+
+#[test]
+fn test_expr_simplify_canonical_form() {
+    let x = Expr::X;
+    let c = Expr::from;
+    let pairs = [
+        (
+            (x.clone() + c(1.0)) * (x.clone() - c(1.0)),
+            x.clone().pow(c(2.0)) - c(1.0),
+        ),
+        (
+            x.clone().sin() * x.clone() + c(2.0),
+            c(1.0) + x.clone() * x.clone().sin() + c(1.0),
+        ),
+        (
+            (x.clone().cos() + x.clone()).pow(c(2.0)),
+            x.clone().cos() * x.clone().cos()
+                + c(2.0) * x.clone() * x.clone().cos()
+                + x.clone().pow(c(2.0)),
+        ),
+        (
+            c(3.0) * x.clone().sin().pow(c(2.0)) + c(2.0) * x.clone().cos().pow(c(2.0)),
+            x.clone().sin().pow(c(2.0)) + c(2.0),
+        ),
+        (
+            x.clone().tan().ln() / x.clone().tan().ln().pow(c(3.0)),
+            c(1.0) / (x.clone().tan().ln() * x.clone().tan().ln()),
+        ),
+    ];
+    for (a, b) in pairs {
+        assert_eq!(a.simplify(), b.simplify());
+    }
+
+    // Simplifying a polynomial gives the same expression as simplifying it as a `Polynomial`.
+    let p1 = Polynomial(vec![
+        Monomial { c: 2.0, e: 1.0 },
+        Monomial { c: 3.0, e: 2.0 },
+        Monomial { c: -1.0, e: 0.0 },
+        Monomial { c: 2.0, e: 2.0 },
+        Monomial { c: 0.0, e: 5.0 },
+        Monomial { c: -2.0, e: -1.0 },
+    ]);
+    let e1 = Expr::from(&p1).simplify();
+    assert_eq!(e1.to_string(), "5 * x^2 + 2 * x - 2 * x^(-1) - 1");
+    assert_eq!(e1, Expr::from(p1.simplified().unwrap()).simplify());
+    for n in 1..4 {
+        let expected = Expr::from(p1.nth_derivative(n).unwrap()).simplify();
+        assert_eq!(Expr::from(&p1).nth_derivative(n).simplify(), expected);
+    }
+}
+
+#[test]
+fn test_expr_simplify_keeps_value() {
+    let x = Expr::X;
+    let c = Expr::from;
+    let e1 = (c(3.0) * x.clone().pow(c(2.0))).sin() * x.clone().exp()
+        + x.clone().pow(x.clone().cos()) / (x.clone().tan() + c(2.0)).ln()
+        - (x.clone() + c(0.5)).pow(c(3.0)) / x.clone().pow(c(0.5));
+    for expression in [e1.clone(), e1.derivative(), e1.nth_derivative(2)] {
+        let simplified = expression.simplify();
+        for point in [0.3_f64, 0.7, 1.1] {
+            let expected = expression.value(point);
+            assert!((simplified.value(point) - expected).abs() <= 1e-9 * expected.abs().max(1.0));
+        }
+    }
+
+    // Cancelling a base against itself does not define the result where the expression is undefined.
+    for (expression, point) in [
+        (x.clone() / x.clone(), 0.0),
+        (x.clone().pow(c(2.0)) / x.clone(), 0.0),
+        (x.clone().pow(c(0.5)) / x.clone().pow(c(0.5)), -1.0),
+        (x.clone().pow(c(0.5)) * x.clone().pow(c(0.5)), -1.0),
+        ((x.clone() + c(1.0)) / (x.clone() + c(1.0)), -1.0),
+        (x.clone().sin() / x.clone().sin(), 0.0),
+    ] {
+        assert!(!expression.value(point).is_finite());
+        assert!(
+            !expression.simplify().value(point).is_finite(),
+            "{expression}"
+        );
+    }
+}
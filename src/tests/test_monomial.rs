@@ -1,4 +1,4 @@
-use crate::Monomial;
+use crate::{CalcError, DomainError, Monomial, Polynomial};
 use crate::tests::monomial_statics::*;
 
 #[test]
@@ -11,6 +11,12 @@ fn test_monomial_identity() {
 }
 
 #[test]
+#[allow(
+    clippy::approx_constant,
+    clippy::excessive_precision,
+    clippy::float_cmp,
+    clippy::unreadable_literal
+)]
 fn test_monomial_fields_access() {
     let m0 = &MONOMIAL_1_0;
     assert_eq!(m0.c, 1_f64);
@@ -37,6 +43,7 @@ fn test_monomial_fields_access() {
 }
 
 #[test]
+#[allow(clippy::float_cmp, clippy::unreadable_literal)]
 fn test_values_for_monomial() {
     let m1 = &MONOMIAL_1_1;
     assert_eq!(m1.value(2_f64), 2_f64);
@@ -68,6 +75,7 @@ fn test_values_for_monomial() {
 }
 
 #[test]
+#[allow(clippy::needless_borrow)]
 fn test_add_monomial_of_same_power() {
     let m1 = &MONOMIAL_1_1;
     let m2 = &MONOMIAL_2_1;
@@ -91,7 +99,8 @@ fn test_add_monomial_of_same_power() {
 }
 
 #[test]
-#[should_panic(expected = "Cannot add monomials with different powers of x.")]
+#[should_panic(expected = "MismatchedExponents")]
+#[allow(clippy::needless_borrow)]
 fn test_panic_on_adding_monomial_of_different_power0() {
     let m1 = &MONOMIAL_1_1;
     let m2 = &MONOMIAL_2_2;
@@ -99,7 +108,8 @@ fn test_panic_on_adding_monomial_of_different_power0() {
 }
 
 #[test]
-#[should_panic(expected = "Cannot add monomials with different powers of x.")]
+#[should_panic(expected = "MismatchedExponents")]
+#[allow(clippy::needless_borrow)]
 fn test_panic_on_adding_monomial_of_different_power1() {
     let m3 = &MONOMIAL_1_0;
     let m4 = &MONOMIAL_2_1;
@@ -107,7 +117,8 @@ fn test_panic_on_adding_monomial_of_different_power1() {
 }
 
 #[test]
-#[should_panic(expected = "Cannot add monomials with different powers of x.")]
+#[should_panic(expected = "MismatchedExponents")]
+#[allow(clippy::needless_borrow)]
 fn test_panic_on_adding_monomial_of_different_power2() {
     let m5 = &MONOMIAL_1_N1;
     let m6 = &MONOMIAL_2_0;
@@ -115,7 +126,8 @@ fn test_panic_on_adding_monomial_of_different_power2() {
 }
 
 #[test]
-#[should_panic(expected = "Cannot add monomials with different powers of x.")]
+#[should_panic(expected = "MismatchedExponents")]
+#[allow(clippy::needless_borrow)]
 fn test_panic_on_adding_monomial_of_different_power3() {
     let m7 = &MONOMIAL_1_N1;
     let m8 = &MONOMIAL_2_1;
@@ -123,6 +135,7 @@ fn test_panic_on_adding_monomial_of_different_power3() {
 }
 
 #[test]
+#[allow(clippy::needless_borrow)]
 fn test_multiply_monomial() {
     let m1 = &MONOMIAL_2_1;
     let m2 = &MONOMIAL_3_2;
@@ -156,6 +169,7 @@ fn test_multiply_monomial() {
 }
 
 #[test]
+#[allow(clippy::float_cmp, clippy::unreadable_literal)]
 fn test_trig_on_monomial() {
     let m1 = Monomial { c: 1.0, e: 1.0 };
     assert_eq!(f64::sin(m1.value(1.0)), 0.8414709848078965);
@@ -168,8 +182,96 @@ fn test_trig_on_monomial() {
 }
 
 #[test]
+#[allow(clippy::default_trait_access, clippy::float_cmp)]
 fn test_monomial_default() {
     let m: Monomial = Default::default();
     assert_eq!(m.c, 0.0);
     assert_eq!(m.e, 0.0);
 }
+
+#[test]
+fn test_monomial_antiderivative() {
+    assert_eq!(MONOMIAL_3_2.antiderivative().unwrap(), MONOMIAL_1_3);
+    assert_eq!(MONOMIAL_2_0.antiderivative().unwrap(), MONOMIAL_2_1);
+    assert_eq!(
+        MONOMIAL_1_N11.antiderivative().unwrap(),
+        Monomial {
+            c: -0.1_f64,
+            e: -10_f64
+        }
+    );
+    assert_eq!(
+        Monomial { c: 3.0, e: -0.5 }.antiderivative().unwrap(),
+        Monomial { c: 6.0, e: 0.5 }
+    );
+
+    for m in [&MONOMIAL_3_3, &MONOMIAL_2_N22, &MONOMIAL_PI_0] {
+        assert!(
+            m.antiderivative()
+                .unwrap()
+                .derivative()
+                .is_equal_within_tolerance_to(m)
+        );
+    }
+}
+
+#[test]
+fn test_monomial_antiderivative_of_reciprocal() {
+    assert_eq!(
+        MONOMIAL_0P5_N1.antiderivative(),
+        Err(CalcError::Domain(DomainError::LogarithmicTerm {
+            coefficient: 0.5
+        }))
+    );
+    assert_eq!(
+        MONOMIAL_1_N1.antiderivative(),
+        Err(CalcError::Domain(DomainError::LogarithmicTerm {
+            coefficient: 1.0
+        }))
+    );
+}
+
+#[test]
+fn test_monomial_display() {
+    assert_eq!(MONOMIAL_3_2.to_string(), "3x^2");
+    assert_eq!(MONOMIAL_1_1.to_string(), "x");
+    assert_eq!(MONOMIAL_1_0.to_string(), "1");
+    assert_eq!(MONOMIAL_0_500.to_string(), "0");
+    assert_eq!(MONOMIAL_N4_0.to_string(), "-4");
+    assert_eq!(MONOMIAL_0P5_N1.to_string(), "0.5x^-1");
+    assert_eq!(MONOMIAL_N34P2_389651P6516.to_string(), "-34.2x^389651.6516");
+    assert_eq!(Monomial { c: -1.0, e: 2.0 }.to_string(), "-x^2");
+    assert_eq!(Monomial { c: 1.0, e: -0.5 }.to_string(), "x^-0.5");
+
+    assert_eq!(format!("{MONOMIAL_PI_0:.4}"), "3.1416");
+    assert_eq!(format!("{MONOMIAL_3_2:.1}"), "3.0x^2");
+    let cube_root = Monomial::new(1.0, 1.0 / 3.0);
+    assert_eq!(format!("{cube_root:.2}"), "x^0.33");
+}
+
+#[test]
+fn test_monomial_operators() {
+    assert_eq!(&MONOMIAL_2_1 * &MONOMIAL_3_2, MONOMIAL_6_3);
+    assert_eq!(-&MONOMIAL_2_0, Monomial { c: -2.0, e: 0.0 });
+    assert_eq!(-MONOMIAL_N4_0.clone(), Monomial { c: 4.0, e: 0.0 });
+    assert_eq!(&MONOMIAL_1_2 * 2.0, MONOMIAL_2_2);
+    assert_eq!(2.0 * &MONOMIAL_1_2, MONOMIAL_2_2);
+    assert_eq!(&MONOMIAL_2_2 / 2.0, MONOMIAL_1_2);
+
+    assert_eq!(
+        &MONOMIAL_1_1 + &MONOMIAL_2_1,
+        Polynomial(vec![MONOMIAL_3_1.clone()])
+    );
+    assert_eq!(
+        &MONOMIAL_1_2 + &MONOMIAL_1_0,
+        Polynomial(vec![MONOMIAL_1_2.clone(), MONOMIAL_1_0.clone()])
+    );
+    assert_eq!(&MONOMIAL_3_1 - &MONOMIAL_3_1, Polynomial::new());
+
+    let mut m = MONOMIAL_1_1.clone();
+    m *= &MONOMIAL_2_1;
+    assert_eq!(m, MONOMIAL_2_2);
+    m *= 3.0;
+    m /= 6.0;
+    assert_eq!(m, MONOMIAL_1_2);
+}
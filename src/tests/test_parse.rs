@@ -0,0 +1,111 @@
+use crate::tests::monomial_statics::*;
+use crate::{Monomial, ParseError, ParseErrorKind, Polynomial};
+
+#[test]
+fn test_parse_monomial() {
+    assert_eq!("3x^2".parse::<Monomial>().unwrap(), MONOMIAL_3_2);
+    assert_eq!("3 * x ** 2".parse::<Monomial>().unwrap(), MONOMIAL_3_2);
+    assert_eq!("x".parse::<Monomial>().unwrap(), MONOMIAL_1_1);
+    assert_eq!("+x".parse::<Monomial>().unwrap(), MONOMIAL_1_1);
+    assert_eq!("-4".parse::<Monomial>().unwrap(), MONOMIAL_N4_0);
+    assert_eq!("0.5x^-1".parse::<Monomial>().unwrap(), MONOMIAL_0P5_N1);
+    assert_eq!("0.5x^(-1)".parse::<Monomial>().unwrap(), MONOMIAL_0P5_N1);
+    assert_eq!(
+        "-34.2x^389651.6516".parse::<Monomial>().unwrap(),
+        MONOMIAL_N34P2_389651P6516
+    );
+    assert_eq!(
+        "2.5e2x^(3/-4)".parse::<Monomial>().unwrap(),
+        Monomial { c: 250.0, e: -0.75 }
+    );
+    assert_eq!(
+        ".5x^1e1".parse::<Monomial>().unwrap(),
+        Monomial { c: 0.5, e: 10.0 }
+    );
+}
+
+#[test]
+#[allow(clippy::unreadable_literal)]
+fn test_parse_polynomial() {
+    assert_eq!(
+        "3x^2 - 2.5x + 1".parse::<Polynomial>().unwrap(),
+        Polynomial(vec![
+            Monomial { c: 3.0, e: 2.0 },
+            Monomial { c: -2.5, e: 1.0 },
+            Monomial { c: 1.0, e: 0.0 },
+        ])
+    );
+    assert_eq!(
+        "-x^-1+x^(1/2)-4*x**3".parse::<Polynomial>().unwrap(),
+        Polynomial(vec![
+            Monomial { c: -1.0, e: -1.0 },
+            Monomial { c: 1.0, e: 0.5 },
+            Monomial { c: -4.0, e: 3.0 },
+        ])
+    );
+    // Terms are not combined.
+    assert_eq!(
+        "x + x".parse::<Polynomial>().unwrap(),
+        Polynomial(vec![MONOMIAL_1_1.clone(), MONOMIAL_1_1.clone()])
+    );
+
+    let p1 = Polynomial(vec![
+        Monomial {
+            c: 6581.0,
+            e: 162.0,
+        },
+        Monomial {
+            c: -2166.0019,
+            e: 1.000014,
+        },
+        Monomial { c: -1.0, e: 0.0 },
+        Monomial { c: 0.7, e: -3.0 },
+    ]);
+    assert_eq!(p1.to_string().parse::<Polynomial>().unwrap(), p1);
+}
+
+#[test]
+fn test_parse_errors() {
+    let error = |position, kind| ParseError { position, kind };
+
+    assert_eq!(
+        "".parse::<Polynomial>(),
+        Err(error(0, ParseErrorKind::Empty))
+    );
+    assert_eq!(
+        "   ".parse::<Monomial>(),
+        Err(error(3, ParseErrorKind::Empty))
+    );
+    assert_eq!(
+        "3x^".parse::<Monomial>(),
+        Err(error(3, ParseErrorKind::UnexpectedEnd))
+    );
+    assert_eq!(
+        "3x + 1".parse::<Monomial>(),
+        Err(error(3, ParseErrorKind::UnexpectedCharacter('+')))
+    );
+    assert_eq!(
+        "3x^2 + y".parse::<Polynomial>(),
+        Err(error(7, ParseErrorKind::UnexpectedCharacter('y')))
+    );
+    assert_eq!(
+        "3x 2".parse::<Polynomial>(),
+        Err(error(3, ParseErrorKind::UnexpectedCharacter('2')))
+    );
+    assert_eq!(
+        "3 * 2".parse::<Polynomial>(),
+        Err(error(4, ParseErrorKind::UnexpectedCharacter('2')))
+    );
+    assert_eq!(
+        "x^(1/2".parse::<Polynomial>(),
+        Err(error(6, ParseErrorKind::UnexpectedEnd))
+    );
+    assert_eq!(
+        "x^(1/0)".parse::<Polynomial>(),
+        Err(error(5, ParseErrorKind::DivisionByZero))
+    );
+    assert_eq!(
+        "1.2.3x".parse::<Polynomial>(),
+        Err(error(0, ParseErrorKind::InvalidNumber("1.2.3".to_string())))
+    );
+}
@@ -1,5 +1,6 @@
 use crate::tests::monomial_statics::*;
-use crate::{Monomial, Polynomial};
+use crate::{CalcError, Concavity, DomainError, Monomial, Polynomial, Trend};
+use std::f64::consts::E;
 
 #[test]
 fn test_polynomial_identity() {
@@ -40,6 +41,7 @@ fn test_new_polynomial() {
 }
 
 #[test]
+#[allow(clippy::float_cmp)]
 fn test_value_polynomial() {
     let p1 = Polynomial(vec![
         Monomial { c: 1_f64, e: 2_f64 },
@@ -50,6 +52,7 @@ fn test_value_polynomial() {
 }
 
 #[test]
+#[allow(clippy::too_many_lines)]
 fn test_simplified() {
     let p1 = Polynomial(vec![
         Monomial { c: 1_f64, e: 1_f64 },
@@ -497,6 +500,7 @@ fn test_derivative() {
 }
 
 #[test]
+#[allow(clippy::unreadable_literal)]
 fn test_is_equal_within_tolerance_to() {
     let p1 = Polynomial(vec![Monomial { c: 1_f64, e: 1_f64 }]);
     let p2 = Polynomial(vec![Monomial { c: 1_f64, e: 1_f64 }]);
@@ -532,6 +536,7 @@ fn test_is_equal_within_tolerance_to() {
 }
 
 #[test]
+#[allow(clippy::unreadable_literal)]
 fn test_nth_derivative() {
     let p1 = Polynomial(vec![
         Monomial { c: 1_f64, e: 4_f64 },
@@ -611,6 +616,7 @@ fn test_nth_derivative() {
 }
 
 #[test]
+#[allow(clippy::unreadable_literal)]
 fn test_trend_over_interval() {
     let p1 = Polynomial(vec![
         Monomial { c: 1.0, e: 2.0 },
@@ -618,11 +624,11 @@ fn test_trend_over_interval() {
         Monomial { c: 1.0, e: 0.0 },
     ]);
 
-    assert_eq!(p1.trend_over_interval(0.0, -1.0), "decreasing");
-    assert_eq!(p1.trend_over_interval(-1.0, 0.0), "decreasing");
+    assert_eq!(p1.trend_over_interval(0.0, -1.0), Trend::Decreasing);
+    assert_eq!(p1.trend_over_interval(-1.0, 0.0), Trend::Decreasing);
 
-    assert_eq!(p1.trend_over_interval(2.0, 6.0), "increasing");
-    assert_eq!(p1.trend_over_interval(6.0, 2.0), "increasing");
+    assert_eq!(p1.trend_over_interval(2.0, 6.0), Trend::Increasing);
+    assert_eq!(p1.trend_over_interval(6.0, 2.0), Trend::Increasing);
 
     let p2 = Polynomial(vec![
         Monomial { c: -5.0, e: 3.0 },
@@ -630,11 +636,11 @@ fn test_trend_over_interval() {
         Monomial { c: -2.0, e: -1.0 },
         Monomial { c: 1.0, e: 0.0 },
     ]);
-    assert_eq!(p2.trend_over_interval(0.0, 1.0), "increasing");
-    assert_eq!(p2.trend_over_interval(1.0, 0.0), "increasing");
+    assert_eq!(p2.trend_over_interval(0.0, 1.0), Trend::Increasing);
+    assert_eq!(p2.trend_over_interval(1.0, 0.0), Trend::Increasing);
 
-    assert_eq!(p2.trend_over_interval(1.0, 6.0), "decreasing");
-    assert_eq!(p2.trend_over_interval(1.0, 6.0), "decreasing");
+    assert_eq!(p2.trend_over_interval(1.0, 6.0), Trend::Decreasing);
+    assert_eq!(p2.trend_over_interval(1.0, 6.0), Trend::Decreasing);
 
     let p3 = Polynomial(vec![
         Monomial {
@@ -647,14 +653,14 @@ fn test_trend_over_interval() {
         },
         Monomial { c: 1.0, e: 0.0 },
     ]);
-    assert_eq!(p3.trend_over_interval(0.0, 1.0), "increasing");
-    assert_eq!(p3.trend_over_interval(1.0, 0.0), "increasing");
+    assert_eq!(p3.trend_over_interval(0.0, 1.0), Trend::Increasing);
+    assert_eq!(p3.trend_over_interval(1.0, 0.0), Trend::Increasing);
 
-    assert_eq!(p3.trend_over_interval(1.0, 6.0), "increasing");
-    assert_eq!(p3.trend_over_interval(6.0, 1.0), "increasing");
+    assert_eq!(p3.trend_over_interval(1.0, 6.0), Trend::Increasing);
+    assert_eq!(p3.trend_over_interval(6.0, 1.0), Trend::Increasing);
 
-    assert_eq!(p3.trend_over_interval(-1.0, 1.0), "undefined");
-    assert_eq!(p3.trend_over_interval(1.0, 1.0), "constant");
+    assert_eq!(p3.trend_over_interval(-1.0, 1.0), Trend::Undefined);
+    assert_eq!(p3.trend_over_interval(1.0, 1.0), Trend::Constant);
 }
 
 #[test]
@@ -665,14 +671,687 @@ fn test_interval_concave_up_down_both_or_neither() {
         Monomial { c: -2.0, e: 1.0 },
         Monomial { c: 1.0, e: 0.0 },
     ]);
-    assert_eq!(p1.concavity_over_interval(0.0, 1.0).unwrap(), "concave up");
-    assert_eq!(p1.concavity_over_interval(1.0, 2.0).unwrap(), "concave up");
-    assert_eq!(p1.concavity_over_interval(-2.0, -0.25).unwrap(), "concave down");
-    assert_eq!(p1.concavity_over_interval(-1.0, 0.5).unwrap(), "undefined");
+    assert_eq!(p1.concavity_over_interval(0.0, 1.0).unwrap(), Concavity::ConcaveUp);
+    assert_eq!(p1.concavity_over_interval(1.0, 2.0).unwrap(), Concavity::ConcaveUp);
+    assert_eq!(p1.concavity_over_interval(-2.0, -0.25).unwrap(), Concavity::ConcaveDown);
+    assert_eq!(p1.concavity_over_interval(-1.0, 0.5).unwrap(), Concavity::Undefined);
 }
 
 #[test]
+#[allow(clippy::default_trait_access)]
 fn test_polynomial_default() {
     let p: Polynomial = Default::default();
     assert_eq!(p.0.len(), 0);
 }
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_antiderivative() {
+    let p1 = Polynomial(vec![
+        Monomial { c: 4.0, e: 3.0 },
+        Monomial { c: 3.0, e: 2.0 },
+        Monomial { c: -2.0, e: 0.0 },
+    ]);
+    let a1 = p1.antiderivative(7.0).unwrap();
+    assert_eq!(
+        a1.polynomial,
+        Polynomial(vec![
+            Monomial { c: 1.0, e: 4.0 },
+            Monomial { c: 1.0, e: 3.0 },
+            Monomial { c: -2.0, e: 1.0 },
+            Monomial { c: 7.0, e: 0.0 },
+        ])
+    );
+    assert_eq!(a1.log_coefficient, 0.0);
+    assert_eq!(a1.polynomial.derivative().unwrap(), p1);
+
+    let p2 = Polynomial(vec![
+        Monomial { c: 2.0, e: -1.0 },
+        Monomial { c: 1.0, e: -2.0 },
+        Monomial { c: 3.0, e: -1.0 },
+    ]);
+    let a2 = p2.antiderivative(0.0).unwrap();
+    assert_eq!(
+        a2.polynomial,
+        Polynomial(vec![Monomial { c: -1.0, e: -1.0 }])
+    );
+    assert_eq!(a2.log_coefficient, 5.0);
+    assert!(a2.has_log_term());
+    assert_eq!(a2.value(1.0), -1.0);
+    assert_eq!(a2.value(-1.0), 1.0);
+
+    let p3 = Polynomial::new();
+    let a3 = p3.antiderivative(0.0).unwrap();
+    assert_eq!(a3.polynomial, Polynomial::new());
+    assert_eq!(a3.value(12.0), 0.0);
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_definite_integral() {
+    let p1 = Polynomial(vec![
+        Monomial { c: 1.0, e: 2.0 },
+        Monomial { c: -2.0, e: 1.0 },
+        Monomial { c: 1.0, e: 0.0 },
+    ]);
+    assert_eq!(p1.definite_integral(0.0, 3.0).unwrap(), 3.0);
+    assert_eq!(p1.definite_integral(3.0, 0.0).unwrap(), -3.0);
+    assert_eq!(p1.definite_integral(1.5, 1.5).unwrap(), 0.0);
+
+    // ln|x| terms, on either side of the singularity at 0.
+    let p2 = Polynomial(vec![Monomial { c: 2.0, e: -1.0 }]);
+    assert!((p2.definite_integral(1.0, E).unwrap() - 2.0).abs() < 1e-12);
+    assert!((p2.definite_integral(-E, -1.0).unwrap() + 2.0).abs() < 1e-12);
+
+    // Convergent improper integrals are evaluated as limits.
+    let p3 = Polynomial(vec![Monomial { c: 1.0, e: -0.5 }]);
+    assert_eq!(p3.definite_integral(0.0, 1.0).unwrap(), 2.0);
+    let p4 = Polynomial(vec![Monomial { c: 1.5, e: 0.5 }]);
+    assert_eq!(p4.definite_integral(0.0, 4.0).unwrap(), 8.0);
+}
+
+#[test]
+fn test_definite_integral_errors() {
+    let p1 = Polynomial(vec![
+        Monomial { c: 1.0, e: 1.0 },
+        Monomial { c: 1.0, e: -2.0 },
+    ]);
+    assert_eq!(
+        p1.definite_integral(-1.0, 1.0),
+        Err(CalcError::Domain(DomainError::DivergentIntegral {
+            exponent: -2.0,
+            singularity: 0.0
+        }))
+    );
+    assert_eq!(
+        p1.definite_integral(0.0, 1.0),
+        Err(CalcError::Domain(DomainError::DivergentIntegral {
+            exponent: -2.0,
+            singularity: 0.0
+        }))
+    );
+    assert!(p1.definite_integral(1.0, 2.0).is_ok());
+
+    let p2 = Polynomial(vec![Monomial { c: 1.0, e: -1.0 }]);
+    assert_eq!(
+        p2.definite_integral(-1.0, 2.0),
+        Err(CalcError::Domain(DomainError::DivergentIntegral {
+            exponent: -1.0,
+            singularity: 0.0
+        }))
+    );
+
+    let p3 = Polynomial(vec![Monomial { c: 1.0, e: 0.5 }]);
+    assert_eq!(
+        p3.definite_integral(-4.0, -1.0),
+        Err(CalcError::Domain(DomainError::FractionalPowerOfNegative {
+            exponent: 0.5
+        }))
+    );
+
+    let p4 = Polynomial(vec![Monomial { c: 1.0, e: 400.0 }]);
+    assert_eq!(
+        p4.definite_integral(0.0, 1e10),
+        Err(CalcError::NonFinite(f64::INFINITY))
+    );
+}
+
+#[test]
+fn test_div_rem() {
+    // (6x^4 + 5x^2 - 3) / (2x^2 + 1) = 3x^2 + 1, remainder -4
+    let p1 = Polynomial(vec![
+        Monomial { c: 6.0, e: 4.0 },
+        Monomial { c: 5.0, e: 2.0 },
+        Monomial { c: -3.0, e: 0.0 },
+    ]);
+    let p2 = Polynomial(vec![
+        Monomial { c: 2.0, e: 2.0 },
+        Monomial { c: 1.0, e: 0.0 },
+    ]);
+    let (quotient, remainder) = p1.div_rem(&p2).unwrap();
+    assert_eq!(
+        quotient,
+        Polynomial(vec![
+            Monomial { c: 3.0, e: 2.0 },
+            Monomial { c: 1.0, e: 0.0 },
+        ])
+    );
+    assert_eq!(remainder, Polynomial(vec![Monomial { c: -4.0, e: 0.0 }]));
+
+    // An exact division leaves no remainder, even with rounding error.
+    let p3 = Polynomial(vec![
+        Monomial { c: 0.1, e: 1.0 },
+        Monomial { c: 0.2, e: 0.0 },
+    ]);
+    let p4 = p3.multiply_polynomial(p2.clone()).unwrap();
+    let (quotient, remainder) = p4.div_rem(&p3).unwrap();
+    assert!(quotient.is_equal_within_tolerance_to(p2.clone()).unwrap());
+    assert_eq!(remainder, Polynomial::new());
+
+    // Dividing by a polynomial of higher degree leaves the dividend as the remainder.
+    let (quotient, remainder) = p2.div_rem(&p1).unwrap();
+    assert_eq!(quotient, Polynomial::new());
+    assert_eq!(remainder, p2);
+
+    assert_eq!(
+        p1.div_rem(&Polynomial::new()),
+        Err(CalcError::Domain(DomainError::DivisionByZero))
+    );
+    assert_eq!(
+        p1.div_rem(&Polynomial(vec![Monomial { c: 1.0, e: -1.0 }])),
+        Err(CalcError::Domain(DomainError::NegativeExponent {
+            exponent: -1.0
+        }))
+    );
+    assert_eq!(
+        p1.div_rem(&Polynomial(vec![Monomial { c: 1.0, e: 0.5 }])),
+        Err(CalcError::Domain(DomainError::NonIntegerExponent {
+            exponent: 0.5
+        }))
+    );
+}
+
+#[test]
+fn test_gcd_and_lcm() {
+    // (x - 1)(x - 2)^2
+    let p1 = Polynomial(vec![
+        Monomial { c: 1.0, e: 3.0 },
+        Monomial { c: -5.0, e: 2.0 },
+        Monomial { c: 8.0, e: 1.0 },
+        Monomial { c: -4.0, e: 0.0 },
+    ]);
+    // 3(x - 2)(x + 1)
+    let p2 = Polynomial(vec![
+        Monomial { c: 3.0, e: 2.0 },
+        Monomial { c: -3.0, e: 1.0 },
+        Monomial { c: -6.0, e: 0.0 },
+    ]);
+    assert!(
+        p1.gcd(&p2)
+            .unwrap()
+            .is_equal_within_tolerance_to(Polynomial(vec![
+                Monomial { c: 1.0, e: 1.0 },
+                Monomial { c: -2.0, e: 0.0 },
+            ]))
+            .unwrap()
+    );
+
+    // (x - 1)(x - 2)^2 (x + 1) = x^4 - 4x^3 + 3x^2 + 4x - 4
+    assert!(
+        p1.lcm(&p2)
+            .unwrap()
+            .is_equal_within_tolerance_to(Polynomial(vec![
+                Monomial { c: 1.0, e: 4.0 },
+                Monomial { c: -4.0, e: 3.0 },
+                Monomial { c: 3.0, e: 2.0 },
+                Monomial { c: 4.0, e: 1.0 },
+                Monomial { c: -4.0, e: 0.0 },
+            ]))
+            .unwrap()
+    );
+
+    // Coprime polynomials have a constant greatest common divisor.
+    let p3 = Polynomial(vec![
+        Monomial { c: 1.0, e: 2.0 },
+        Monomial { c: 1.0, e: 0.0 },
+    ]);
+    assert_eq!(
+        p1.gcd(&p3).unwrap(),
+        Polynomial(vec![Monomial { c: 1.0, e: 0.0 }])
+    );
+
+    assert_eq!(
+        p2.gcd(&Polynomial::new()).unwrap(),
+        p2.multiply_polynomial(Polynomial(vec![Monomial {
+            c: 1.0 / 3.0,
+            e: 0.0
+        }]))
+        .unwrap()
+    );
+    assert_eq!(
+        Polynomial::new().gcd(&Polynomial::new()).unwrap(),
+        Polynomial::new()
+    );
+    assert_eq!(p1.lcm(&Polynomial::new()).unwrap(), Polynomial::new());
+}
+
+#[test]
+fn test_polynomial_display() {
+    let p1 = Polynomial(vec![
+        Monomial { c: 3.0, e: 2.0 },
+        Monomial { c: -4.0, e: 1.0 },
+        Monomial { c: 1.0, e: 0.0 },
+    ]);
+    assert_eq!(p1.to_string(), "3x^2 - 4x + 1");
+
+    let p2 = Polynomial(vec![
+        Monomial { c: -1.0, e: 3.0 },
+        Monomial { c: 0.0, e: 2.0 },
+        Monomial { c: 1.0, e: 1.0 },
+        Monomial { c: -0.5, e: -1.0 },
+    ]);
+    assert_eq!(p2.to_string(), "-x^3 + x - 0.5x^-1");
+    assert_eq!(format!("{p2:.2}"), "-x^3 + x - 0.50x^-1");
+
+    let p3 = Polynomial(vec![Monomial { c: 0.0, e: 2.0 }]);
+    assert_eq!(p3.to_string(), "0");
+    assert_eq!(Polynomial::new().to_string(), "0");
+
+    let a1 = Polynomial(vec![
+        Monomial { c: 1.0, e: -1.0 },
+        Monomial { c: 1.0, e: -2.0 },
+    ])
+    .antiderivative(0.0)
+    .unwrap();
+    assert_eq!(a1.to_string(), "ln|x| - x^-1");
+}
+
+#[test]
+fn test_polynomial_operators() {
+    let p1 = Polynomial(vec![
+        Monomial { c: 1.0, e: 2.0 },
+        Monomial { c: 2.0, e: 1.0 },
+    ]);
+    let p2 = Polynomial(vec![
+        Monomial { c: 3.0, e: 2.0 },
+        Monomial { c: -1.0, e: 0.0 },
+    ]);
+
+    assert_eq!(&p1 + &p2, p1.add_polynomial(p2.clone()).unwrap());
+    assert_eq!(&p1 * &p2, p1.multiply_polynomial(p2.clone()).unwrap());
+    assert_eq!(
+        &p1 - &p2,
+        Polynomial(vec![
+            Monomial { c: -2.0, e: 2.0 },
+            Monomial { c: 2.0, e: 1.0 },
+            Monomial { c: 1.0, e: 0.0 },
+        ])
+    );
+    assert_eq!(&p1 - &p1, Polynomial::new());
+    assert_eq!(
+        -&p1,
+        Polynomial(vec![
+            Monomial { c: -1.0, e: 2.0 },
+            Monomial { c: -2.0, e: 1.0 },
+        ])
+    );
+    assert_eq!(
+        p1.clone() + p2.clone(),
+        &p1 + &p2,
+        "owned and borrowed operands agree"
+    );
+    assert_eq!(p1.clone() * &p2, &p1 * p2.clone());
+
+    assert_eq!(&p1 * 2.0, 2.0 * &p1);
+    assert_eq!(
+        &p1 * 2.0,
+        Polynomial(vec![
+            Monomial { c: 2.0, e: 2.0 },
+            Monomial { c: 4.0, e: 1.0 },
+        ])
+    );
+    assert_eq!(&p1 * 0.0, Polynomial::new());
+    assert_eq!(&(&p1 * 4.0) / 4.0, p1);
+
+    let mut p3 = p1.clone();
+    p3 += &p2;
+    p3 -= p2.clone();
+    assert_eq!(p3, p1);
+    p3 *= &p2;
+    assert_eq!(p3, &p1 * &p2);
+    p3 /= 2.0;
+    p3 *= 2.0;
+    assert_eq!(p3, &p1 * &p2);
+}
+
+#[test]
+fn test_polynomial_monomial_operators() {
+    let p1 = Polynomial(vec![
+        Monomial { c: 1.0, e: 2.0 },
+        Monomial { c: 2.0, e: 1.0 },
+    ]);
+
+    assert_eq!(
+        &p1 + &MONOMIAL_3_0,
+        Polynomial(vec![
+            Monomial { c: 1.0, e: 2.0 },
+            Monomial { c: 2.0, e: 1.0 },
+            Monomial { c: 3.0, e: 0.0 },
+        ])
+    );
+    assert_eq!(&p1 - &MONOMIAL_2_1, Polynomial(vec![MONOMIAL_1_2.clone()]));
+    assert_eq!(
+        &p1 * &MONOMIAL_3_1,
+        Polynomial(vec![
+            Monomial { c: 3.0, e: 3.0 },
+            Monomial { c: 6.0, e: 2.0 },
+        ])
+    );
+
+    let mut p2 = p1.clone();
+    p2 += MONOMIAL_1_2.clone();
+    p2 -= &MONOMIAL_1_2;
+    p2 *= &MONOMIAL_1_0;
+    assert_eq!(p2, p1);
+}
+
+#[test]
+fn test_trend_and_concavity_display() {
+    assert_eq!(Trend::Increasing.to_string(), "increasing");
+    assert_eq!(Trend::Decreasing.to_string(), "decreasing");
+    assert_eq!(Trend::Constant.to_string(), "constant");
+    assert_eq!(Trend::Undefined.to_string(), "undefined");
+    assert_eq!(Concavity::ConcaveUp.to_string(), "concave up");
+    assert_eq!(Concavity::ConcaveDown.to_string(), "concave down");
+    assert_eq!(Concavity::Undefined.to_string(), "undefined");
+}
+
+#[test]
+fn test_canonical_form() {
+    let p1 = Polynomial(vec![
+        Monomial { c: 1.0, e: 0.0 },
+        Monomial { c: 2.0, e: 3.0 },
+        Monomial { c: 4.0, e: 0.0 },
+        Monomial { c: -2.0, e: 3.0 },
+        Monomial {
+            c: 1.0,
+            e: f64::NAN,
+        },
+        Monomial { c: 5.0, e: 1.0 },
+    ]);
+    assert!(!p1.is_canonical());
+    let combined = p1.simplify_by_combining_alike_powers().unwrap();
+    assert_eq!(
+        combined.0[..2],
+        [Monomial { c: 5.0, e: 0.0 }, Monomial { c: 0.0, e: 3.0 }]
+    );
+    assert!(combined.0[2].e.is_nan());
+    assert_eq!(combined.0[3], Monomial { c: 5.0, e: 1.0 });
+    let simplified = p1.simplified().unwrap();
+    assert_eq!(
+        simplified.0[..2],
+        [Monomial { c: 5.0, e: 1.0 }, Monomial { c: 5.0, e: 0.0 }]
+    );
+    assert!(simplified.0[2].e.is_nan());
+
+    let p2 = Polynomial(vec![
+        Monomial { c: 1.0, e: 4.0 },
+        Monomial { c: 3.0, e: 2.0 },
+        Monomial { c: -1.0, e: 0.0 },
+    ]);
+    let p3 = Polynomial(vec![
+        Monomial { c: 2.0, e: 3.0 },
+        Monomial { c: -3.0, e: 2.0 },
+        Monomial { c: 1.0, e: 0.0 },
+        Monomial { c: 1.0, e: -1.0 },
+    ]);
+    assert!(p2.is_canonical() && p3.is_canonical());
+    let sum = &p2 + &p3;
+    assert!(sum.is_canonical());
+    assert_eq!(
+        sum,
+        Polynomial(vec![
+            Monomial { c: 1.0, e: 4.0 },
+            Monomial { c: 2.0, e: 3.0 },
+            Monomial { c: 1.0, e: -1.0 },
+        ])
+    );
+    let p4 = Polynomial(p3.0.iter().rev().cloned().collect());
+    assert!(!p4.is_canonical());
+    assert_eq!(&p2 + &p4, sum);
+}
+
+#[test]
+fn test_multiply_large_polynomials() {
+    // (1 + x + ... + x^1999)(1 - x) = 1 - x^2000
+    let p1 = Polynomial(
+        (0..2000)
+            .map(|e| Monomial {
+                c: 1.0,
+                e: f64::from(e),
+            })
+            .collect(),
+    );
+    let p2 = Polynomial(vec![
+        Monomial { c: -1.0, e: 1.0 },
+        Monomial { c: 1.0, e: 0.0 },
+    ]);
+    let product = &p1 * &p2;
+    assert!(product.is_canonical());
+    assert_eq!(
+        product,
+        Polynomial(vec![
+            Monomial { c: -1.0, e: 2000.0 },
+            Monomial { c: 1.0, e: 0.0 },
+        ])
+    );
+
+    let p3 = Polynomial(p1.0[..500].to_vec());
+    assert_eq!((&p3 * &p3).0.len(), 999);
+
+    // Fractional exponents, given in no particular order, are multiplied monomial by monomial.
+    let p4 = Polynomial(
+        (0..300)
+            .rev()
+            .map(|e| Monomial {
+                c: 1.0,
+                e: f64::from(e) + 0.5,
+            })
+            .collect(),
+    );
+    let p5 = Polynomial(vec![
+        Monomial { c: -1.0, e: 0.0 },
+        Monomial { c: 0.0, e: 2.0 },
+        Monomial { c: 1.0, e: 1.0 },
+    ]);
+    assert_eq!(
+        &p4 * &p5,
+        Polynomial(vec![
+            Monomial { c: 1.0, e: 300.5 },
+            Monomial { c: -1.0, e: 0.5 },
+        ])
+    );
+}
+
+#[test]
+fn test_fast_polynomial_multiplication() {
+    // (x^-1 + 1 + x + ... + x^98)(x - 1) = x^99 - x^-1
+    let p1 = Polynomial((-1..99).map(|e| Monomial::new(1.0, f64::from(e))).collect());
+    let p2 = Polynomial(
+        (0..40)
+            .map(|e| Monomial::new(f64::from(e), f64::from(e)))
+            .collect(),
+    );
+    let p3 = Polynomial(vec![Monomial::new(1.0, 1.0), Monomial::new(-1.0, 0.0)]);
+    assert_eq!(
+        &p1 * &p3,
+        Polynomial(vec![Monomial::new(1.0, 99.0), Monomial::new(-1.0, -1.0)])
+    );
+
+    // The Karatsuba product matches the product of every pair of monomials.
+    let fast = &p1 * &p2;
+    let mut pairwise = vec![];
+    for a in &p1.0 {
+        for b in &p2.0 {
+            pairwise.push(a * b);
+        }
+    }
+    assert_eq!(fast, Polynomial(pairwise).simplified().unwrap());
+    assert!(fast.is_canonical());
+
+    // Sparse polynomials are multiplied pair by pair.
+    let p4 = Polynomial(
+        (0..40)
+            .map(|e| Monomial::new(1.0, f64::from(e * 1000)))
+            .collect(),
+    );
+    assert_eq!((&p4 * &p4).0.len(), 79);
+}
+
+#[test]
+fn test_values() {
+    let xs: Vec<f64> = (0..100).map(|i| f64::from(i) * 0.1 - 5.0).collect();
+
+    // Dense polynomials are evaluated with Horner's method.
+    let p1 = Polynomial(vec![
+        Monomial { c: 0.5, e: 3.0 },
+        Monomial { c: -2.0, e: 1.0 },
+        Monomial { c: 1.0, e: 3.0 },
+        Monomial { c: 4.0, e: 0.0 },
+    ]);
+    let values = p1.values(&xs);
+    for (x, value) in xs.iter().zip(&values) {
+        assert!((value - p1.value(*x)).abs() < 1e-12);
+    }
+
+    // Other polynomials are evaluated term by term.
+    let p2 = Polynomial(vec![
+        Monomial { c: 1.0, e: 0.5 },
+        Monomial { c: 1.0, e: -1.0 },
+    ]);
+    let mut values = vec![0.0; xs.len()];
+    p2.evaluate_into(&xs, &mut values);
+    for (x, value) in xs.iter().zip(&values) {
+        assert_eq!(value.to_bits(), p2.value(*x).to_bits());
+    }
+
+    let p3 = Polynomial(vec![Monomial { c: 1.0, e: 1100.0 }]);
+    assert_eq!(p3.values(&[1.0, -1.0, 2.0]), vec![1.0, 1.0, f64::INFINITY]);
+    assert_eq!(Polynomial::new().values(&[1.0, 2.0]), vec![0.0, 0.0]);
+}
+
+#[test]
+#[allow(clippy::unreadable_literal)]
+fn test_compose_and_substitute_affine() {
+    let p1 = Polynomial(vec![
+        Monomial { c: 2.0, e: 3.0 },
+        Monomial { c: -1.0, e: 1.0 },
+        Monomial { c: 5.0, e: 0.0 },
+    ]);
+    let xs = [-2.0, -0.5, 0.0, 1.0, 3.0];
+
+    // Affine inner polynomials.
+    let p2 = p1.substitute_affine(-3.0, 0.5).unwrap();
+    for x in xs {
+        assert!((p2.value(x) - p1.value(-3.0 * x + 0.5)).abs() < 1e-9);
+    }
+    let inner = Polynomial(vec![
+        Monomial { c: 0.5, e: 0.0 },
+        Monomial { c: -3.0, e: 1.0 },
+    ]);
+    assert_eq!(p1.compose(&inner).unwrap(), p2);
+    assert_eq!(p1.substitute_affine(1.0, 0.0).unwrap(), p1);
+    assert_eq!(
+        p1.substitute_affine(0.0, 2.0).unwrap(),
+        Polynomial(vec![Monomial { c: 19.0, e: 0.0 }])
+    );
+
+    // The binomial coefficients of (x + 1)^20 are exact.
+    let p3 = Polynomial(vec![Monomial { c: 1.0, e: 20.0 }]);
+    let shifted = p3.substitute_affine(1.0, 1.0).unwrap();
+    assert_eq!(shifted.0.len(), 21);
+    assert_eq!(
+        shifted.0[10],
+        Monomial {
+            c: 184756.0,
+            e: 10.0
+        }
+    );
+
+    // Dense inner polynomials.
+    let inner = Polynomial(vec![
+        Monomial { c: 1.0, e: 2.0 },
+        Monomial { c: -1.0, e: 0.0 },
+    ]);
+    let p4 = p1.compose(&inner).unwrap();
+    assert!(p4.is_canonical());
+    for x in xs {
+        assert!((p4.value(x) - p1.value(inner.value(x))).abs() < 1e-9);
+    }
+
+    // Other inner polynomials.
+    let inner = Polynomial(vec![
+        Monomial { c: 1.0, e: 0.5 },
+        Monomial { c: 1.0, e: -1.0 },
+    ]);
+    let p5 = p1.compose(&inner).unwrap();
+    for x in [0.25, 1.0, 4.0] {
+        assert!((p5.value(x) - p1.value(inner.value(x))).abs() < 1e-9);
+    }
+
+    assert_eq!(
+        Polynomial::new().compose(&inner).unwrap(),
+        Polynomial::new()
+    );
+    let p6 = Polynomial(vec![Monomial { c: 1.0, e: -1.0 }]);
+    assert_eq!(
+        p6.compose(&inner),
+        Err(CalcError::Domain(DomainError::NegativeExponent {
+            exponent: -1.0
+        }))
+    );
+    assert_eq!(
+        p6.substitute_affine(1.0, 1.0),
+        Err(CalcError::Domain(DomainError::NegativeExponent {
+            exponent: -1.0
+        }))
+    );
+}
+
+#[test]
+fn test_taylor_coefficients_and_recenter() {
+    let p1 = Polynomial(vec![
+        Monomial { c: 0.5, e: 4.0 },
+        Monomial { c: -3.0, e: 2.0 },
+        Monomial { c: 2.0, e: 1.0 },
+        Monomial { c: 7.0, e: 0.0 },
+    ]);
+
+    // The coefficients are the derivatives divided by k!.
+    let coefficients = p1.taylor_coefficients_at(-1.5, 6).unwrap();
+    assert_eq!(coefficients.len(), 7);
+    let mut factorial = 1.0;
+    for (k, coefficient) in (0..7).zip(&coefficients) {
+        if k > 0 {
+            factorial *= f64::from(k);
+        }
+        let derivative = p1.nth_derivative(k).unwrap().value(-1.5);
+        assert!((coefficient * factorial - derivative).abs() < 1e-9);
+    }
+    assert_eq!(p1.taylor_coefficients_at(0.0, 0).unwrap(), vec![7.0]);
+
+    // recenter() gives the same coefficients as a polynomial.
+    let q1 = p1.recenter(-1.5).unwrap();
+    assert_eq!(
+        q1.0.iter().map(|element| element.c).collect::<Vec<_>>(),
+        coefficients[..5].iter().rev().copied().collect::<Vec<_>>()
+    );
+    for x in [-3.0, -1.5, 0.0, 2.0] {
+        assert!((q1.value(x + 1.5) - p1.value(x)).abs() < 1e-9);
+    }
+
+    // 1/x = 1/2 - (x - 2)/4 + (x - 2)^2/8 - ...
+    let p2 = Polynomial(vec![Monomial { c: 1.0, e: -1.0 }]);
+    assert_eq!(
+        p2.taylor_coefficients_at(2.0, 3).unwrap(),
+        vec![0.5, -0.25, 0.125, -0.0625]
+    );
+    assert_eq!(
+        p2.taylor_coefficients_at(0.0, 3),
+        Err(CalcError::NonFinite(f64::INFINITY))
+    );
+    assert_eq!(
+        p2.recenter(2.0),
+        Err(CalcError::Domain(DomainError::NegativeExponent {
+            exponent: -1.0
+        }))
+    );
+
+    let p3 = Polynomial(vec![Monomial { c: 1.0, e: 0.5 }]);
+    assert_eq!(
+        p3.taylor_coefficients_at(-1.0, 3),
+        Err(CalcError::Domain(DomainError::FractionalPowerOfNegative {
+            exponent: 0.5
+        }))
+    );
+}
@@ -0,0 +1,191 @@
+use crate::{CalcError, DomainError, Monomial, ParseErrorKind, Polynomial, Rational, Scalar};
+
+fn r(numerator: i64, denominator: i64) -> Rational {
+    Rational::new(numerator, denominator)
+}
+
+#[test]
+fn test_rational_normalization() {
+    assert_eq!(r(2, 4), r(1, 2));
+    assert_eq!(r(3, -9).numerator(), -1);
+    assert_eq!(r(3, -9).denominator(), 3);
+    assert_eq!(r(0, -5), Rational::ZERO);
+    assert_eq!(Rational::default(), Rational::ZERO);
+    assert!(r(10, 5).is_integer());
+    assert!(!r(10, 4).is_integer());
+}
+
+#[test]
+#[should_panic(expected = "divide by zero")]
+fn test_rational_zero_denominator() {
+    let _ = r(1, 0);
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_rational_arithmetic() {
+    assert_eq!(r(1, 10) + r(2, 10), r(3, 10));
+    assert_eq!(r(1, 2) - r(1, 3), r(1, 6));
+    assert_eq!(r(2, 3) * r(9, 4), r(3, 2));
+    assert_eq!(r(2, 3) / r(-4, 3), r(-1, 2));
+    assert_eq!(-r(1, 3), r(-1, 3));
+    assert_eq!(r(1, 3).to_f64(), 1.0 / 3.0);
+    assert_eq!(f64::from(r(3, 4)), 0.75);
+
+    assert!(r(1, 3) < r(1, 2));
+    assert!(r(-1, 2) < r(-1, 3));
+    assert_eq!(r(7, 3).max(r(5, 2)), r(5, 2));
+}
+
+#[test]
+fn test_rational_pow() {
+    assert_eq!(r(8, 27).pow(&r(2, 3)), r(4, 9));
+    assert_eq!(r(-8, 1).pow(&r(1, 3)), r(-2, 1));
+    assert_eq!(r(4, 9).pow(&r(-1, 2)), r(3, 2));
+    assert_eq!(r(0, 1).pow(&r(0, 1)), Rational::ONE);
+    assert_eq!(r(0, 1).pow(&r(1, 2)), Rational::ZERO);
+    assert_eq!(r(1, 1).pow(&r(i64::MAX, 3)), Rational::ONE);
+
+    assert_eq!(
+        r(2, 1).checked_pow(&r(1, 2)),
+        Err(CalcError::Domain(DomainError::NonRationalPower {
+            base: 2.0,
+            exponent: 0.5
+        }))
+    );
+    assert_eq!(
+        r(-4, 1).checked_pow(&r(1, 2)),
+        Err(CalcError::Domain(DomainError::FractionalPowerOfNegative {
+            exponent: 0.5
+        }))
+    );
+    assert_eq!(
+        r(0, 1).checked_pow(&r(-2, 1)),
+        Err(CalcError::Domain(DomainError::NonRationalPower {
+            base: 0.0,
+            exponent: -2.0
+        }))
+    );
+    assert_eq!(
+        r(2, 1).checked_powi(63),
+        Err(CalcError::Overflow(2_f64.powi(63)))
+    );
+    assert_eq!(r(2, 1).checked_powi(62), Ok(r(1 << 62, 1)));
+}
+
+#[test]
+fn test_rational_checked_arithmetic() {
+    assert_eq!(r(1, 2).checked_sub(r(1, 3)), Ok(r(1, 6)));
+    assert_eq!(r(2, 3).checked_mul(r(9, 4)), Ok(r(3, 2)));
+    assert_eq!(
+        r(i64::MAX, 1).checked_add(r(1, 1)),
+        Err(CalcError::Overflow(9_223_372_036_854_775_808.0))
+    );
+    assert!(r(1, i64::MAX).checked_mul(r(1, 2)).is_err());
+    assert!(r(1, 1).checked_div(Rational::ZERO).is_err());
+
+    // x^(1/2) + 1
+    let p1 = Polynomial(vec![
+        Monomial::new(r(1, 1), r(1, 2)),
+        Monomial::new(r(1, 1), Rational::ZERO),
+    ]);
+    assert_eq!(p1.checked_value(r(9, 4)), Ok(r(5, 2)));
+    assert_eq!(
+        p1.checked_value(r(2, 1)),
+        Err(CalcError::Domain(DomainError::NonRationalPower {
+            base: 2.0,
+            exponent: 0.5
+        }))
+    );
+    assert_eq!(
+        p1.checked_value(r(-1, 1)),
+        Err(CalcError::Domain(DomainError::FractionalPowerOfNegative {
+            exponent: 0.5
+        }))
+    );
+    let p2 = Polynomial(vec![Monomial::new(r(i64::MAX, 1), r(1, 1))]);
+    assert!(p2.checked_value(r(2, 1)).is_err());
+    assert_eq!(p2.checked_value(r(1, 1)), Ok(r(i64::MAX, 1)));
+}
+
+#[test]
+#[should_panic(expected = "2^(0.5) is not a rational number.")]
+fn test_rational_pow_of_irrational_result() {
+    let my_polynomial = Polynomial(vec![Monomial::new(r(1, 1), r(1, 2))]);
+    let _ = my_polynomial.value(r(2, 1));
+}
+
+#[test]
+fn test_rational_parse_display_and_conversion() {
+    assert_eq!("1/3".parse::<Rational>().unwrap(), r(1, 3));
+    assert_eq!(" -4 / 6 ".parse::<Rational>().unwrap(), r(-2, 3));
+    assert_eq!(
+        "1/x".parse::<Rational>().unwrap_err().kind,
+        ParseErrorKind::InvalidNumber("x".to_string())
+    );
+    assert_eq!(
+        "1/0".parse::<Rational>().unwrap_err().kind,
+        ParseErrorKind::DivisionByZero
+    );
+    assert_eq!(r(-1, 3).to_string(), "-1/3");
+    assert_eq!(r(6, 3).to_string(), "2");
+
+    assert_eq!(Rational::try_from(-2.5), Ok(r(-5, 2)));
+    assert_eq!(
+        Rational::try_from(1e18),
+        Ok(r(1_000_000_000_000_000_000, 1))
+    );
+    assert_eq!(Rational::try_from(1e-300), Ok(Rational::ZERO));
+    assert_eq!(
+        Rational::try_from(f64::INFINITY),
+        Err(CalcError::NonFinite(f64::INFINITY))
+    );
+    assert_eq!(Rational::try_from(1e19), Err(CalcError::Overflow(1e19)));
+}
+
+#[test]
+fn test_rational_polynomial_round_trip() {
+    // (1/3)x^3 + x^(1/3)
+    let p = Polynomial(vec![
+        Monomial::new(r(1, 3), r(3, 1)),
+        Monomial::new(r(1, 1), r(1, 3)),
+    ]);
+    let derivative = p.derivative().unwrap();
+    assert_eq!(
+        derivative,
+        Polynomial(vec![
+            Monomial::new(r(1, 1), r(2, 1)),
+            Monomial::new(r(1, 3), r(-2, 3)),
+        ])
+    );
+    assert_eq!(derivative.to_string(), "x^2 + (1/3)x^(-2/3)");
+
+    let antiderivative = derivative.antiderivative(r(1, 2)).unwrap();
+    assert_eq!(
+        antiderivative.polynomial,
+        &p + &Polynomial(vec![Monomial::new(r(1, 2), Rational::ZERO)])
+    );
+    assert_eq!(antiderivative.to_string(), "(1/3)x^3 + x^(1/3) + 1/2");
+    assert_eq!(p.value(r(8, 1)), r(518, 3));
+
+    let with_log = Polynomial(vec![Monomial::new(r(2, 3), r(-1, 1))])
+        .antiderivative(Rational::ZERO)
+        .unwrap();
+    assert_eq!(with_log.to_string(), "(2/3)ln|x|");
+}
+
+#[test]
+fn test_rational_exponents_are_only_alike_when_equal() {
+    let p = Polynomial(vec![
+        Monomial::new(r(1, 10), r(1, 3)),
+        Monomial::new(r(2, 10), r(1, 3)),
+        Monomial::new(r(1, 1), r(333_333_333_333, 1_000_000_000_000)),
+    ]);
+    assert_eq!(
+        p.simplified().unwrap(),
+        Polynomial(vec![
+            Monomial::new(r(3, 10), r(1, 3)),
+            Monomial::new(r(1, 1), r(333_333_333_333, 1_000_000_000_000)),
+        ])
+    );
+}
@@ -0,0 +1,233 @@
+use crate::{CalcError, Complex, DomainError, Monomial, Polynomial, RealRoot};
+
+fn assert_roots(actual: &[RealRoot], expected: &[(f64, u32)]) {
+    assert_eq!(actual.len(), expected.len(), "{actual:?}");
+    for (root, (x, multiplicity)) in actual.iter().zip(expected) {
+        assert!((root.x - x).abs() < 1e-8, "{actual:?}");
+        assert_eq!(root.multiplicity, *multiplicity, "{actual:?}");
+    }
+}
+
+#[test]
+fn test_real_roots() {
+    // (x - 1)(x - 2)(x - 3) = x^3 - 6x^2 + 11x - 6
+    let p1 = Polynomial(vec![
+        Monomial { c: 1.0, e: 3.0 },
+        Monomial { c: -6.0, e: 2.0 },
+        Monomial { c: 11.0, e: 1.0 },
+        Monomial { c: -6.0, e: 0.0 },
+    ]);
+    assert_roots(
+        &p1.real_roots(1e-12).unwrap(),
+        &[(1.0, 1), (2.0, 1), (3.0, 1)],
+    );
+    // A tolerance below the spacing of floats still stops.
+    assert_roots(
+        &p1.real_roots(f64::MIN_POSITIVE).unwrap(),
+        &[(1.0, 1), (2.0, 1), (3.0, 1)],
+    );
+
+    // x^2 + 1 has no real roots.
+    let p2 = Polynomial(vec![
+        Monomial { c: 1.0, e: 2.0 },
+        Monomial { c: 1.0, e: 0.0 },
+    ]);
+    assert_roots(&p2.real_roots(1e-12).unwrap(), &[]);
+
+    // x^2 - 2, given out of order.
+    let p3 = Polynomial(vec![
+        Monomial { c: -2.0, e: 0.0 },
+        Monomial { c: 1.0, e: 2.0 },
+    ]);
+    assert_roots(
+        &p3.real_roots(1e-12).unwrap(),
+        &[
+            (-std::f64::consts::SQRT_2, 1),
+            (std::f64::consts::SQRT_2, 1),
+        ],
+    );
+
+    // Constants have no roots.
+    let p4 = Polynomial(vec![Monomial { c: 5.0, e: 0.0 }]);
+    assert_roots(&p4.real_roots(1e-12).unwrap(), &[]);
+}
+
+#[test]
+fn test_real_roots_multiplicities() {
+    // x^3 (x - 1)^2 (x + 2) = x^6 - 3x^4 + 2x^3
+    let p1 = Polynomial(vec![
+        Monomial { c: 1.0, e: 6.0 },
+        Monomial { c: -3.0, e: 4.0 },
+        Monomial { c: 2.0, e: 3.0 },
+    ]);
+    assert_roots(
+        &p1.real_roots(1e-12).unwrap(),
+        &[(-2.0, 1), (0.0, 3), (1.0, 2)],
+    );
+
+    // (x + 1)^4 = x^4 + 4x^3 + 6x^2 + 4x + 1
+    let p2 = Polynomial(vec![
+        Monomial { c: 1.0, e: 4.0 },
+        Monomial { c: 4.0, e: 3.0 },
+        Monomial { c: 6.0, e: 2.0 },
+        Monomial { c: 4.0, e: 1.0 },
+        Monomial { c: 1.0, e: 0.0 },
+    ]);
+    assert_roots(&p2.real_roots(1e-12).unwrap(), &[(-1.0, 4)]);
+
+    // (x^2 + 1)(x - 3)^2 has a double real root and no others.
+    let p3 = Polynomial(vec![
+        Monomial { c: 1.0, e: 2.0 },
+        Monomial { c: 1.0, e: 0.0 },
+    ])
+    .multiply_polynomial(Polynomial(vec![
+        Monomial { c: 1.0, e: 2.0 },
+        Monomial { c: -6.0, e: 1.0 },
+        Monomial { c: 9.0, e: 0.0 },
+    ]))
+    .unwrap();
+    assert_roots(&p3.real_roots(1e-12).unwrap(), &[(3.0, 2)]);
+}
+
+#[test]
+fn test_real_roots_negative_exponents() {
+    // 1 - 1/x^2 is undefined at 0, which is not a root.
+    let p1 = Polynomial(vec![
+        Monomial { c: 1.0, e: 0.0 },
+        Monomial { c: -1.0, e: -2.0 },
+    ]);
+    assert_roots(&p1.real_roots(1e-12).unwrap(), &[(-1.0, 1), (1.0, 1)]);
+
+    // x^2 + 1/x = (x^3 + 1) / x
+    let p2 = Polynomial(vec![
+        Monomial { c: 1.0, e: 2.0 },
+        Monomial { c: 1.0, e: -1.0 },
+    ]);
+    assert_roots(&p2.real_roots(1e-12).unwrap(), &[(-1.0, 1)]);
+}
+
+#[test]
+fn test_real_roots_errors() {
+    let p1 = Polynomial(vec![Monomial { c: 1.0, e: 0.5 }]);
+    assert_eq!(
+        p1.real_roots(1e-12),
+        Err(CalcError::Domain(DomainError::NonIntegerExponent {
+            exponent: 0.5
+        }))
+    );
+
+    let p2 = Polynomial(vec![Monomial { c: 0.0, e: 2.0 }]);
+    assert_eq!(
+        p2.real_roots(1e-12),
+        Err(CalcError::Domain(DomainError::InfinitelyManyRoots))
+    );
+
+    let p3 = Polynomial(vec![Monomial { c: 1.0, e: 2.0 }]);
+    assert_eq!(
+        p3.real_roots(0.0),
+        Err(CalcError::Domain(DomainError::InvalidTolerance(0.0)))
+    );
+    assert!(p3.real_roots(f64::NAN).is_err());
+
+    let p4 = Polynomial(vec![
+        Monomial {
+            c: 1.0,
+            e: 2_147_483_647.0,
+        },
+        Monomial {
+            c: 1.0,
+            e: -2_147_483_647.0,
+        },
+    ]);
+    assert_eq!(
+        p4.real_roots(1e-12),
+        Err(CalcError::Domain(DomainError::DegreeTooLarge {
+            degree: 4_294_967_294.0
+        }))
+    );
+
+    let p5 = Polynomial(vec![Monomial { c: 1.0, e: 3e9 }]);
+    assert_eq!(
+        p5.real_roots(1e-12),
+        Err(CalcError::Domain(DomainError::DegreeTooLarge {
+            degree: 3e9
+        }))
+    );
+
+    let p6 = Polynomial(vec![
+        Monomial { c: 1.0, e: 1e9 },
+        Monomial { c: 1.0, e: 0.0 },
+    ]);
+    assert_eq!(
+        p6.complex_roots(),
+        Err(CalcError::Domain(DomainError::DegreeTooLarge {
+            degree: 1e9
+        }))
+    );
+}
+
+#[test]
+fn test_complex_roots() {
+    // (x - 2)(x^2 + 1) = x^3 - 2x^2 + x - 2
+    let p1 = Polynomial(vec![
+        Monomial { c: 1.0, e: 3.0 },
+        Monomial { c: -2.0, e: 2.0 },
+        Monomial { c: 1.0, e: 1.0 },
+        Monomial { c: -2.0, e: 0.0 },
+    ]);
+    let roots = p1.complex_roots().unwrap();
+    let expected = [
+        Complex::new(0.0, -1.0),
+        Complex::new(0.0, 1.0),
+        Complex::new(2.0, 0.0),
+    ];
+    assert_eq!(roots.len(), expected.len());
+    for (root, z) in roots.iter().zip(expected) {
+        assert!((root.z - z).norm() < 1e-12, "{roots:?}");
+        assert!(root.residual < 1e-12);
+        assert!(root.error_bound >= (root.z - z).norm());
+    }
+
+    // x^5 - 1 has the fifth roots of unity as its roots.
+    let p2 = Polynomial(vec![
+        Monomial { c: 1.0, e: 5.0 },
+        Monomial { c: -1.0, e: 0.0 },
+    ]);
+    let roots = p2.complex_roots().unwrap();
+    assert_eq!(roots.len(), 5);
+    for root in &roots {
+        assert!((root.z.norm() - 1.0).abs() < 1e-12);
+    }
+    assert_eq!(roots.iter().filter(|root| root.z.is_real()).count(), 1);
+}
+
+#[test]
+fn test_complex_roots_zero_and_negative_exponents() {
+    // x^2 (x + 1) has a double root at 0.
+    let p1 = Polynomial(vec![
+        Monomial { c: 1.0, e: 3.0 },
+        Monomial { c: 1.0, e: 2.0 },
+    ]);
+    let roots = p1.complex_roots().unwrap();
+    assert_eq!(roots.len(), 3);
+    assert!((roots[0].z - Complex::new(-1.0, 0.0)).norm() < 1e-12);
+    assert_eq!(roots[1].z, Complex::default());
+    assert_eq!(roots[2].z, Complex::default());
+
+    // x + 4/x = (x^2 + 4) / x
+    let p2 = Polynomial(vec![
+        Monomial { c: 1.0, e: 1.0 },
+        Monomial { c: 4.0, e: -1.0 },
+    ]);
+    let roots = p2.complex_roots().unwrap();
+    assert_eq!(roots.len(), 2);
+    assert!((roots[0].z - Complex::new(0.0, -2.0)).norm() < 1e-12);
+    assert!((roots[1].z - Complex::new(0.0, 2.0)).norm() < 1e-12);
+
+    assert!(Polynomial::new().complex_roots().is_err());
+    assert!(
+        Polynomial(vec![Monomial { c: 1.0, e: 1.5 }])
+            .complex_roots()
+            .is_err()
+    );
+}
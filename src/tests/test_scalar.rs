@@ -0,0 +1,110 @@
+use crate::{Complex, Monomial, Polynomial, Scalar};
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_f32_polynomial() {
+    let p1: Polynomial<f32> = Polynomial(vec![
+        Monomial { c: 1.0, e: 2.0 },
+        Monomial { c: 2.0, e: 1.0 },
+        Monomial { c: 3.0, e: 2.0 },
+    ]);
+    assert_eq!(p1.value(2.0), 20.0_f32);
+    assert_eq!(
+        p1.simplified().unwrap(),
+        Polynomial(vec![
+            Monomial { c: 4.0_f32, e: 2.0 },
+            Monomial { c: 2.0, e: 1.0 },
+        ])
+    );
+    assert_eq!(
+        p1.nth_derivative(2).unwrap(),
+        Polynomial(vec![Monomial { c: 8.0_f32, e: 0.0 }])
+    );
+
+    let p2 = &p1
+        * &Polynomial(vec![Monomial {
+            c: 0.5_f32,
+            e: -1.0,
+        }]);
+    assert_eq!(
+        p2,
+        Polynomial(vec![
+            Monomial { c: 2.0_f32, e: 1.0 },
+            Monomial { c: 1.0, e: 0.0 },
+        ])
+    );
+
+    let antiderivative = p2.antiderivative(3.0).unwrap();
+    assert_eq!(
+        antiderivative.polynomial,
+        Polynomial(vec![
+            Monomial { c: 1.0_f32, e: 2.0 },
+            Monomial { c: 1.0, e: 1.0 },
+            Monomial { c: 3.0, e: 0.0 },
+        ])
+    );
+    assert!(!antiderivative.has_log_term());
+}
+
+#[test]
+fn test_f32_tolerance() {
+    assert!(1.0_f32.is_equal_within_tolerance_to(&1.000_001));
+    assert!(!1.0_f32.is_equal_within_tolerance_to(&1.001));
+
+    // Exponents which are alike within the tolerance of f32 are combined.
+    let p1: Polynomial<f32> = Polynomial(vec![
+        Monomial { c: 1.0, e: 2.0 },
+        Monomial {
+            c: 1.0,
+            e: 2.000_001,
+        },
+    ]);
+    assert_eq!(p1.simplified().unwrap().0.len(), 1);
+}
+
+#[test]
+fn test_complex_polynomial() {
+    // (x + i)(x - i) = x^2 + 1
+    let p1 = Polynomial(vec![
+        Monomial::new(Complex::from(1.0), Complex::from(1.0)),
+        Monomial::new(Complex::I, Complex::from(0.0)),
+    ]);
+    let p2 = Polynomial(vec![
+        Monomial::new(Complex::from(1.0), Complex::from(1.0)),
+        Monomial::new(-Complex::I, Complex::from(0.0)),
+    ]);
+    let product = &p1 * &p2;
+    assert_eq!(
+        product,
+        Polynomial(vec![
+            Monomial::new(Complex::from(1.0), Complex::from(2.0)),
+            Monomial::new(Complex::from(1.0), Complex::from(0.0)),
+        ])
+    );
+    assert_eq!(product.value(Complex::I), Complex::from(0.0));
+    assert_eq!(
+        product.value(Complex::new(1.0, 1.0)),
+        Complex::new(1.0, 2.0)
+    );
+    assert_eq!(
+        product.derivative().unwrap(),
+        Polynomial(vec![Monomial::new(Complex::from(2.0), Complex::from(1.0))])
+    );
+}
+
+#[test]
+fn test_complex_pow() {
+    assert_eq!(
+        Complex::new(0.0, 2.0).pow(&Complex::from(-1.0)),
+        Complex::new(0.0, -0.5)
+    );
+    assert_eq!(
+        Complex::from(0.0).pow(&Complex::from(0.5)),
+        Complex::from(0.0)
+    );
+    assert_eq!(
+        Complex::from(0.0).pow(&Complex::from(0.0)),
+        Complex::from(1.0)
+    );
+    assert!((Complex::from(-4.0).pow(&Complex::from(0.5)) - Complex::new(0.0, 2.0)).norm() < 1e-15);
+}
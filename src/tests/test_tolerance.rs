@@ -0,0 +1,119 @@
+use crate::{CalcError, Complex, DomainError, Monomial, Polynomial, Rational, Scalar, Tolerance};
+
+#[test]
+#[allow(clippy::unreadable_literal)]
+fn test_tolerance_modes() {
+    assert_eq!(Tolerance::DEFAULT, Tolerance::Absolute(1e-10));
+
+    let absolute = Tolerance::Absolute(1e-10);
+    assert!(absolute.is_equal(0.1 + 0.2, 0.3));
+    assert!(absolute.is_equal(1e-14, -1e-14));
+    assert!(!absolute.is_equal(241346513.3452231, 241346513.3452232));
+    assert!(Tolerance::Absolute(0.0).is_equal(2.5, 2.5));
+    assert!(!Tolerance::Absolute(0.0).is_equal(0.1 + 0.2, 0.3));
+
+    let relative = Tolerance::Relative(1e-12);
+    assert!(relative.is_equal(241346513.3452231, 241346513.3452232));
+    assert!(!relative.is_equal(1e-14, 2e-14));
+    assert!(relative.is_equal(0.0, -0.0));
+    assert!(!relative.is_equal(0.0, 1e-300));
+
+    let ulps = Tolerance::Ulps(2);
+    assert!(ulps.is_equal(1.0, 1.0 + 2.0 * f64::EPSILON));
+    assert!(!ulps.is_equal(1.0, 1.0 + 3.0 * f64::EPSILON));
+    assert!(ulps.is_equal(-0.0, f64::from_bits(1)));
+    assert!(ulps.is_equal(f64::INFINITY, f64::INFINITY));
+    assert!(!ulps.is_equal(f64::NAN, f64::NAN));
+}
+
+#[test]
+fn test_tolerance_scalars() {
+    assert!(1.0_f32.is_equal_within(&(1.0 + f32::EPSILON), Tolerance::Ulps(1)));
+    assert!(!1.0_f32.is_equal_within(&(1.0 + 2.0 * f32::EPSILON), Tolerance::Ulps(1)));
+    assert!(1.0_f32.is_equal_within_tolerance_to(&1.000_001));
+
+    let z = Complex::new(3e8, 4e8);
+    let w = Complex::new(3e8, 4e8 + 1e-3);
+    assert!(!z.is_equal_within(&w, Tolerance::Absolute(1e-10)));
+    assert!(z.is_equal_within(&w, Tolerance::Relative(1e-11)));
+
+    let third = Rational::new(1, 3);
+    assert!(!third.is_equal_within(&Rational::new(333_333, 1_000_000), Tolerance::Absolute(1.0)));
+    assert!(third.is_equal_within(&Rational::new(2, 6), Tolerance::Absolute(0.0)));
+}
+
+#[test]
+#[allow(clippy::unreadable_literal)]
+fn test_tolerance_polynomials() {
+    let p1 = Polynomial(vec![
+        Monomial {
+            c: 241346513.3452231,
+            e: 2.0,
+        },
+        Monomial { c: 1e-14, e: 1.0 },
+    ]);
+    let p2 = Polynomial(vec![
+        Monomial {
+            c: 241346513.3452232,
+            e: 2.0,
+        },
+        Monomial { c: 1.1e-14, e: 1.0 },
+    ]);
+    assert!(!p1.is_equal_within(&p2, Tolerance::Absolute(1e-10)));
+    assert!(!p1.is_equal_within(&p2, Tolerance::Relative(1e-12)));
+    assert!(p1.is_equal_within(&p2, Tolerance::Relative(0.1)));
+
+    assert_eq!(p1.simplified_with_tolerance(Tolerance::Relative(1e-12)), p1);
+    assert_eq!(p1.simplified().unwrap(), p1);
+    assert_eq!(
+        p1.simplified_with_tolerance(Tolerance::Absolute(1e-10)),
+        Polynomial(vec![Monomial {
+            c: 241346513.3452231,
+            e: 2.0
+        }])
+    );
+    assert_eq!(
+        p1.eliminate_zero_coefficients_with_tolerance(Tolerance::Ulps(0)),
+        p1
+    );
+
+    // Tiny coefficients survive arithmetic unless a tolerance is given.
+    let tiny = Polynomial(vec![
+        Monomial { c: 1e-12, e: 3.0 },
+        Monomial { c: 1e-12, e: 1.0 },
+    ]);
+    assert_eq!(tiny.derivative().unwrap().0.len(), 2);
+    assert_eq!(&tiny + &Polynomial::new(), tiny);
+    assert_eq!((&tiny * 2.0).0.len(), 2);
+
+    let p3 = Polynomial(vec![
+        Monomial { c: 1.0, e: 1e-14 },
+        Monomial { c: 1.0, e: 2e-14 },
+    ]);
+    assert_eq!(
+        p3.simplified().unwrap(),
+        Polynomial(vec![Monomial { c: 2.0, e: 1e-14 }])
+    );
+    assert_eq!(
+        p3.simplified_with_tolerance(Tolerance::Relative(1e-12))
+            .0
+            .len(),
+        2
+    );
+}
+
+#[test]
+fn test_set_invalid_global_tolerance() {
+    for tolerance in [
+        Tolerance::Absolute(-1.0),
+        Tolerance::Relative(f64::NAN),
+        Tolerance::Absolute(f64::INFINITY),
+    ] {
+        let Err(CalcError::Domain(DomainError::InvalidTolerance(_))) =
+            Tolerance::set_global(tolerance)
+        else {
+            panic!("{tolerance:?} should be rejected");
+        };
+    }
+    assert_eq!(Tolerance::global(), Tolerance::DEFAULT);
+}
@@ -0,0 +1,151 @@
+//! The `Tolerance` type, which decides when two floating point numbers are close enough to be treated as equal.
+//!
+//! Deciding whether two exponents are alike, or two polynomials are equal, uses the crate-wide default tolerance unless a `Tolerance` is given explicitly.
+//! The default starts as `Tolerance::DEFAULT` (an absolute tolerance of `1e-10`) and can be changed with `Tolerance::set_global()`.
+//!
+//! Only coefficients of exactly `0` are eliminated unless a tolerance is given, so tiny coefficients such as `1e-12` are kept.
+//! Internal algorithms, such as division, root finding and integration, use fixed tolerances of their own and are not affected by the default.
+//!
+//! #### Example
+//! ```rust,standalone_crate
+//! use calcucalc::{Monomial, Polynomial, Tolerance};
+//!
+//! let p1 = Polynomial(vec![Monomial { c: 241346513.3, e: 2.0 }]);
+//! let p2 = Polynomial(vec![Monomial { c: 241346513.30001, e: 2.0 }]);
+//! assert!(!p1.is_equal_within_tolerance_to(p2.clone()).unwrap());
+//!
+//! Tolerance::set_global(Tolerance::Relative(1e-12)).unwrap();
+//! assert!(p1.is_equal_within_tolerance_to(p2.clone()).unwrap());
+//! ```
+
+use std::sync::{PoisonError, RwLock};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{CalcError, DomainError};
+
+/// The crate-wide default tolerance.
+static GLOBAL: RwLock<Tolerance> = RwLock::new(Tolerance::DEFAULT);
+
+/// How close two numbers must be to be treated as equal.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Serialize)]
+pub enum Tolerance {
+    /// The numbers may differ by at most this amount.
+    Absolute(f64),
+    /// The numbers may differ by at most this fraction of the larger of their magnitudes.
+    ///
+    /// Only exactly `0` is equal to `0` under a relative tolerance.
+    Relative(f64),
+    /// The numbers may be at most this many representable values apart.
+    Ulps(u64),
+}
+
+impl Tolerance {
+    /// The initial crate-wide default, an absolute tolerance of `1e-10`.
+    pub const DEFAULT: Self = Self::Absolute(1e-10);
+
+    /// The current crate-wide default tolerance.
+    #[must_use]
+    pub fn global() -> Self {
+        *GLOBAL.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Replaces the crate-wide default tolerance, for every thread.
+    ///
+    /// As the default is shared by every thread, changing it while another thread is comparing polynomials can change that thread's results.
+    /// Prefer the methods which take a `Tolerance` explicitly, such as `Polynomial::simplified_with_tolerance()`, when different parts of a program need different tolerances.
+    ///
+    /// #### Example
+    /// ```rust,standalone_crate
+    /// use calcucalc::Tolerance;
+    ///
+    /// Tolerance::set_global(Tolerance::Ulps(4)).unwrap();
+    /// assert_eq!(Tolerance::global(), Tolerance::Ulps(4));
+    /// assert!(Tolerance::set_global(Tolerance::Absolute(-1.0)).is_err());
+    /// ```
+    ///
+    /// ## Errors
+    ///
+    /// If the tolerance is an absolute or relative tolerance which is negative or not finite, a `DomainError::InvalidTolerance` is returned and the default is left unchanged.
+    pub fn set_global(tolerance: Self) -> Result<(), CalcError> {
+        if let Self::Absolute(amount) | Self::Relative(amount) = tolerance
+            && !(amount.is_finite() && amount >= 0_f64)
+        {
+            return Err(DomainError::InvalidTolerance(amount).into());
+        }
+        *GLOBAL.write().unwrap_or_else(PoisonError::into_inner) = tolerance;
+        Ok(())
+    }
+
+    /// Checks if two `f64` values are equal within this tolerance.
+    ///
+    /// #### Example
+    /// ```rust
+    /// use calcucalc::Tolerance;
+    ///
+    /// assert!(Tolerance::Absolute(1e-10).is_equal(0.1 + 0.2, 0.3));
+    /// assert!(Tolerance::Absolute(1e-10).is_equal(1e-14, 2e-14));
+    /// assert!(!Tolerance::Relative(1e-3).is_equal(1e-14, 2e-14));
+    /// assert!(Tolerance::Relative(1e-12).is_equal(241346513.3, 241346513.30001));
+    /// assert!(Tolerance::Ulps(1).is_equal(1.0, 1.0 + f64::EPSILON));
+    /// ```
+    #[must_use]
+    #[allow(clippy::float_cmp)]
+    pub fn is_equal(&self, a: f64, b: f64) -> bool {
+        if a == b {
+            return true;
+        }
+        match *self {
+            Self::Absolute(amount) => (a - b).abs() <= amount,
+            Self::Relative(amount) => (a - b).abs() <= amount * a.abs().max(b.abs()),
+            Self::Ulps(ulps) => {
+                ulps_between(ordered_f64(a), ordered_f64(b)).is_some_and(|between| between <= ulps)
+            }
+        }
+    }
+
+    /// Checks if two `f32` values are equal within this tolerance, counting units in the last place of an `f32`.
+    #[must_use]
+    #[allow(clippy::float_cmp)]
+    pub(crate) fn is_equal_f32(&self, a: f32, b: f32) -> bool {
+        match *self {
+            Self::Ulps(ulps) => {
+                a == b
+                    || ulps_between(ordered_f32(a), ordered_f32(b))
+                        .is_some_and(|between| between <= ulps)
+            }
+            _ => self.is_equal(f64::from(a), f64::from(b)),
+        }
+    }
+}
+
+/// Maps the bits of an `f64` onto integers which are in the same order as the numbers, or `None` for `NaN`.
+fn ordered_f64(x: f64) -> Option<i128> {
+    if x.is_nan() {
+        return None;
+    }
+    let bits = i64::from_ne_bytes(x.to_ne_bytes());
+    Some(if bits < 0 {
+        i128::from(i64::MIN) - i128::from(bits)
+    } else {
+        i128::from(bits)
+    })
+}
+
+/// Maps the bits of an `f32` onto integers which are in the same order as the numbers, or `None` for `NaN`.
+fn ordered_f32(x: f32) -> Option<i128> {
+    if x.is_nan() {
+        return None;
+    }
+    let bits = i32::from_ne_bytes(x.to_ne_bytes());
+    Some(if bits < 0 {
+        i128::from(i32::MIN) - i128::from(bits)
+    } else {
+        i128::from(bits)
+    })
+}
+
+/// The number of representable values between two ordered floating point numbers.
+fn ulps_between(a: Option<i128>, b: Option<i128>) -> Option<u64> {
+    u64::try_from((a? - b?).unsigned_abs()).ok()
+}